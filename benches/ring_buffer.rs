@@ -0,0 +1,112 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::collections::VecDeque;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use sized_chunks::RingBuffer;
+use typenum::{U100, U128, U64, U65};
+
+const ROUNDS: usize = 1024;
+
+macro_rules! ring_buffers {
+    ($group:expr, $bench:ident) => {
+        $bench!(
+            $group,
+            "RingBuffer<U64>",
+            RingBuffer::<usize, U64>::new(),
+            64
+        );
+        $bench!(
+            $group,
+            "RingBuffer<U65>",
+            RingBuffer::<usize, U65>::new(),
+            65
+        );
+        $bench!(
+            $group,
+            "RingBuffer<U100>",
+            RingBuffer::<usize, U100>::new(),
+            100
+        );
+        $bench!(
+            $group,
+            "RingBuffer<U128>",
+            RingBuffer::<usize, U128>::new(),
+            128
+        );
+        $bench!(
+            $group,
+            "VecDeque(64)",
+            VecDeque::<usize>::with_capacity(64),
+            64
+        );
+        $bench!(
+            $group,
+            "VecDeque(128)",
+            VecDeque::<usize>::with_capacity(128),
+            128
+        );
+    };
+}
+
+/// Push to the back and pop from the front of a half full buffer, so the
+/// contents keep wrapping around the end of the storage.
+macro_rules! push_pop {
+    ($group:expr, $name:expr, $empty:expr, $size:expr) => {
+        $group.bench_function(BenchmarkId::new($name, $size), |b| {
+            let mut buffer = $empty;
+            for i in 0..$size / 2 {
+                buffer.push_back(i);
+            }
+            b.iter(|| {
+                for i in 0..ROUNDS {
+                    buffer.push_back(i);
+                    black_box(buffer.pop_front());
+                }
+            })
+        });
+    };
+}
+
+/// Read every index of a full buffer whose contents wrap around the end of
+/// the storage.
+macro_rules! index {
+    ($group:expr, $name:expr, $empty:expr, $size:expr) => {
+        $group.bench_function(BenchmarkId::new($name, $size), |b| {
+            let mut buffer = $empty;
+            for i in 0..$size {
+                buffer.push_back(i);
+            }
+            for i in 0..$size / 3 {
+                buffer.pop_front();
+                buffer.push_back(i);
+            }
+            b.iter(|| {
+                let mut sum = 0;
+                for i in 0..$size {
+                    sum += buffer[black_box(i)];
+                }
+                sum
+            })
+        });
+    };
+}
+
+fn push_pop(c: &mut Criterion) {
+    let mut group = c.benchmark_group("push_pop");
+    group.throughput(Throughput::Elements(ROUNDS as u64));
+    ring_buffers!(group, push_pop);
+    group.finish();
+}
+
+fn index(c: &mut Criterion) {
+    let mut group = c.benchmark_group("index");
+    ring_buffers!(group, index);
+    group.finish();
+}
+
+criterion_group!(ring_buffer, push_pop, index);
+criterion_main!(ring_buffer);
@@ -12,10 +12,7 @@ mod assert;
 use assert::assert_panic;
 
 #[derive(Arbitrary, Debug)]
-enum Construct<A>
-where
-    A: Arbitrary,
-{
+enum Construct<A> {
     Empty,
     Single(A),
     Pair((A, A)),
@@ -27,10 +24,7 @@ where
 }
 
 #[derive(Arbitrary, Debug)]
-enum Action<A>
-where
-    A: Arbitrary,
-{
+enum Action<A> {
     PushFront(A),
     PushBack(A),
     PopFront,
@@ -52,7 +46,7 @@ where
 
 impl<A> Construct<A>
 where
-    A: Arbitrary + Clone + Debug + Eq,
+    A: Clone + Debug + Eq,
 {
     fn make(self) -> RingBuffer<A> {
         match self {
@@ -2,147 +2,218 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-use bitmaps::Bits;
-use std::iter;
+use bitmaps::{Bitmap, Bits};
+use std::mem;
+use std::ops::Range;
 
-use ::arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+use ::arbitrary::{size_hint, Arbitrary, MaxRecursionReached, Result, Unstructured};
 
+use crate::ring_buffer::{Slice, SliceMut};
 use crate::{types::ChunkLength, Chunk, InlineArray, RingBuffer, SparseChunk};
 
-fn empty<T: 'static>() -> Box<dyn Iterator<Item = T>> {
-    Box::new(iter::empty())
+/// Draw a length of up to `capacity` elements of type `A`, based on how much
+/// data is left in `u`.
+fn arbitrary_len<'a, A: Arbitrary<'a>>(u: &mut Unstructured<'a>, capacity: usize) -> Result<usize> {
+    Ok(u.arbitrary_len::<A>()?.min(capacity))
 }
 
-fn shrink_collection<T: Clone, A: Arbitrary>(
-    entries: impl Iterator<Item = T>,
-    f: impl Fn(&T) -> Box<dyn Iterator<Item = A>>,
-) -> Box<dyn Iterator<Item = Vec<A>>> {
-    let entries: Vec<_> = entries.collect();
-    if entries.is_empty() {
-        return empty();
-    }
-
-    let mut shrinkers: Vec<Vec<_>> = vec![];
-    let mut i = entries.len();
-    loop {
-        shrinkers.push(entries.iter().take(i).map(&f).collect());
-        i /= 2;
-        if i == 0 {
-            break;
-        }
-    }
-    Box::new(iter::once(Vec::new()).chain(iter::from_fn(move || loop {
-        let mut shrinker = shrinkers.pop()?;
-        let x: Option<Vec<A>> = shrinker.iter_mut().map(|s| s.next()).collect();
-        if x.is_none() {
-            continue;
-        }
-        shrinkers.push(shrinker);
-        return x;
-    })))
+/// Size hint for a collection of up to `capacity` elements of type `A`,
+/// including the length prefix `Unstructured::arbitrary_len` consumes.
+fn collection_size_hint<'a, A: Arbitrary<'a>>(
+    depth: usize,
+    capacity: usize,
+) -> std::result::Result<(usize, Option<usize>), MaxRecursionReached> {
+    size_hint::try_recursion_guard(depth, |depth| {
+        let (_, upper) = A::try_size_hint(depth)?;
+        Ok(size_hint::and(
+            (0, Some(mem::size_of::<u32>())),
+            (0, upper.and_then(|upper| upper.checked_mul(capacity))),
+        ))
+    })
 }
 
-impl<A, N> Arbitrary for Chunk<A, N>
+/// Draw an arbitrary subrange of `0..len`.
+fn arbitrary_range(u: &mut Unstructured<'_>, len: usize) -> Result<Range<usize>> {
+    let start = u.int_in_range(0..=len)?;
+    let end = u.int_in_range(start..=len)?;
+    Ok(start..end)
+}
+
+impl<'a, A, N> Arbitrary<'a> for Chunk<A, N>
 where
-    A: Arbitrary,
+    A: Arbitrary<'a>,
     N: ChunkLength<A> + 'static,
 {
-    fn arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
-        u.arbitrary_iter()?.take(Self::CAPACITY).collect()
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = arbitrary_len::<A>(u, Self::CAPACITY)?;
+        let mut chunk = Self::new();
+        for _ in 0..len {
+            chunk.push_back(A::arbitrary(u)?);
+        }
+        Ok(chunk)
     }
 
-    fn arbitrary_take_rest(u: Unstructured<'_>) -> Result<Self> {
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
         u.arbitrary_take_rest_iter()?.take(Self::CAPACITY).collect()
     }
 
     fn size_hint(depth: usize) -> (usize, Option<usize>) {
-        size_hint::recursion_guard(depth, |depth| {
-            let (_, upper) = A::size_hint(depth);
-            (0, upper.map(|upper| upper * Self::CAPACITY))
-        })
+        Self::try_size_hint(depth).unwrap_or_default()
     }
 
-    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
-        let collections = shrink_collection(self.iter(), |x| x.shrink());
-        Box::new(collections.map(|entries| entries.into_iter().collect()))
+    fn try_size_hint(
+        depth: usize,
+    ) -> std::result::Result<(usize, Option<usize>), MaxRecursionReached> {
+        collection_size_hint::<A>(depth, Self::CAPACITY)
     }
 }
 
-impl<A, N> Arbitrary for RingBuffer<A, N>
+impl<'a, A, N> Arbitrary<'a> for RingBuffer<A, N>
 where
-    A: Arbitrary,
+    A: Arbitrary<'a>,
     N: ChunkLength<A> + 'static,
 {
-    fn arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
-        u.arbitrary_iter()?.take(Self::CAPACITY).collect()
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = arbitrary_len::<A>(u, Self::CAPACITY)?;
+        let mut buffer = Self::new();
+        for _ in 0..len {
+            buffer.push_back(A::arbitrary(u)?);
+        }
+        Ok(buffer)
     }
 
-    fn arbitrary_take_rest(u: Unstructured<'_>) -> Result<Self> {
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
         u.arbitrary_take_rest_iter()?.take(Self::CAPACITY).collect()
     }
 
     fn size_hint(depth: usize) -> (usize, Option<usize>) {
-        size_hint::recursion_guard(depth, |depth| {
-            let (_, upper) = A::size_hint(depth);
-            (0, upper.map(|upper| upper * Self::CAPACITY))
-        })
+        Self::try_size_hint(depth).unwrap_or_default()
     }
 
-    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
-        let collections = shrink_collection(self.iter(), |x| x.shrink());
-        Box::new(collections.map(|entries| entries.into_iter().collect()))
+    fn try_size_hint(
+        depth: usize,
+    ) -> std::result::Result<(usize, Option<usize>), MaxRecursionReached> {
+        collection_size_hint::<A>(depth, Self::CAPACITY)
     }
 }
 
-impl<A, N> Arbitrary for SparseChunk<A, N>
+impl<A, N> RingBuffer<A, N>
 where
-    A: Clone,
-    Option<A>: Arbitrary,
-    N: ChunkLength<A> + Bits + 'static,
+    N: ChunkLength<A>,
 {
-    fn arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
-        u.arbitrary_iter()?.take(Self::CAPACITY).collect()
+    /// Get a `Slice` of an arbitrary subrange of the buffer.
+    ///
+    /// This is only available with the `arbitrary` feature enabled.
+    pub fn arbitrary_slice(&self, u: &mut Unstructured<'_>) -> Result<Slice<'_, A, N>> {
+        let range = arbitrary_range(u, self.len())?;
+        Ok(self.slice(range))
     }
 
-    fn arbitrary_take_rest(u: Unstructured<'_>) -> Result<Self> {
-        u.arbitrary_take_rest_iter()?.take(Self::CAPACITY).collect()
+    /// Get a `SliceMut` of an arbitrary subrange of the buffer.
+    ///
+    /// This is only available with the `arbitrary` feature enabled.
+    pub fn arbitrary_slice_mut(&mut self, u: &mut Unstructured<'_>) -> Result<SliceMut<'_, A, N>> {
+        let range = arbitrary_range(u, self.len())?;
+        Ok(self.slice_mut(range))
     }
+}
 
-    fn size_hint(depth: usize) -> (usize, Option<usize>) {
-        size_hint::recursion_guard(depth, |depth| {
-            let (_, upper) = Option::<A>::size_hint(depth);
-            (0, upper.map(|upper| upper * Self::CAPACITY))
-        })
+impl<'a, A, N> Arbitrary<'a> for SparseChunk<A, N>
+where
+    A: Arbitrary<'a>,
+    N: ChunkLength<A> + Bits + 'static,
+{
+    /// Draw the chunk's bitmap a byte at a time, then a value for every index
+    /// set in it.
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut map: Bitmap<N> = Bitmap::new();
+        for base in (0..Self::CAPACITY).step_by(8) {
+            let byte = u8::arbitrary(u)?;
+            for bit in 0..8.min(Self::CAPACITY - base) {
+                map.set(base + bit, byte & (1 << bit) != 0);
+            }
+        }
+        let mut chunk = Self::new();
+        for index in &map {
+            chunk.insert(index, A::arbitrary(u)?);
+        }
+        Ok(chunk)
     }
 
-    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
-        let collections = shrink_collection(self.clone().option_drain(), |x| x.shrink());
-        Box::new(collections.map(|entries| entries.into_iter().collect()))
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        Self::try_size_hint(depth).unwrap_or_default()
+    }
+
+    fn try_size_hint(
+        depth: usize,
+    ) -> std::result::Result<(usize, Option<usize>), MaxRecursionReached> {
+        let bitmap_size = Self::CAPACITY.div_ceil(8);
+        size_hint::try_recursion_guard(depth, |depth| {
+            let (_, upper) = A::try_size_hint(depth)?;
+            Ok(size_hint::and(
+                (bitmap_size, Some(bitmap_size)),
+                (0, upper.and_then(|upper| upper.checked_mul(Self::CAPACITY))),
+            ))
+        })
     }
 }
 
-impl<A, T> Arbitrary for InlineArray<A, T>
+impl<'a, A, T> Arbitrary<'a> for InlineArray<A, T>
 where
-    A: Arbitrary,
+    A: Arbitrary<'a>,
     T: 'static,
 {
-    fn arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
-        u.arbitrary_iter()?.take(Self::CAPACITY).collect()
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = arbitrary_len::<A>(u, Self::CAPACITY)?;
+        let mut array = Self::new();
+        for _ in 0..len {
+            array.push(A::arbitrary(u)?);
+        }
+        Ok(array)
     }
 
-    fn arbitrary_take_rest(u: Unstructured<'_>) -> Result<Self> {
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
         u.arbitrary_take_rest_iter()?.take(Self::CAPACITY).collect()
     }
 
     fn size_hint(depth: usize) -> (usize, Option<usize>) {
-        size_hint::recursion_guard(depth, |depth| {
-            let (_, upper) = A::size_hint(depth);
-            (0, upper.map(|upper| upper * Self::CAPACITY))
-        })
+        Self::try_size_hint(depth).unwrap_or_default()
     }
 
-    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
-        let collections = shrink_collection(self.iter(), |x| x.shrink());
-        Box::new(collections.map(|entries| entries.into_iter().collect()))
+    fn try_size_hint(
+        depth: usize,
+    ) -> std::result::Result<(usize, Option<usize>), MaxRecursionReached> {
+        collection_size_hint::<A>(depth, Self::CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use typenum::{U20, U8};
+
+    #[test]
+    fn generates_within_capacity() {
+        let data: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&data);
+        let chunk = Chunk::<u8, U8>::arbitrary(&mut u).unwrap();
+        assert!(chunk.len() <= 8);
+        let buffer = RingBuffer::<u8, U8>::arbitrary(&mut u).unwrap();
+        assert!(buffer.len() <= 8);
+        let array = InlineArray::<u8, [usize; 2]>::arbitrary(&mut u).unwrap();
+        assert!(array.len() <= 8);
+        let slice = buffer.arbitrary_slice(&mut u).unwrap();
+        assert!(slice.len() <= buffer.len());
+    }
+
+    #[test]
+    fn sparse_chunk_uses_bitmap() {
+        let data = [0b1000_0001, 0b0000_0100, 0b0001_0000, 1, 2, 3, 4];
+        let mut u = Unstructured::new(&data);
+        let chunk = SparseChunk::<u8, U20>::arbitrary(&mut u).unwrap();
+        // Bit 4 of the third byte would be index 20, which is out of range.
+        assert_eq!(vec![0, 7, 10], chunk.indices().collect::<Vec<_>>());
+        assert_eq!(vec![&1, &2, &3], chunk.iter().collect::<Vec<_>>());
+        assert_eq!((3, Some(3 + 20)), SparseChunk::<u8, U20>::size_hint(0));
     }
 }
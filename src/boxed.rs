@@ -0,0 +1,32 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Helpers for constructing values directly on the heap.
+
+use std::alloc::{alloc, handle_alloc_error, Layout};
+use std::mem::MaybeUninit;
+use std::ptr::NonNull;
+
+/// Allocate uninitialised memory for a `T` on the heap, without ever placing a
+/// `T` on the stack.
+pub(crate) fn new_uninit<T>() -> Box<MaybeUninit<T>> {
+    let layout = Layout::new::<MaybeUninit<T>>();
+    if layout.size() == 0 {
+        return unsafe { Box::from_raw(NonNull::dangling().as_ptr()) };
+    }
+    let ptr = unsafe { alloc(layout) } as *mut MaybeUninit<T>;
+    if ptr.is_null() {
+        handle_alloc_error(layout);
+    }
+    unsafe { Box::from_raw(ptr) }
+}
+
+/// Convert a box of initialised memory into a box of `T`.
+///
+/// # Safety
+///
+/// The contents of the box must be a valid `T`.
+pub(crate) unsafe fn assume_init<T>(boxed: Box<MaybeUninit<T>>) -> Box<T> {
+    Box::from_raw(Box::into_raw(boxed) as *mut T)
+}
@@ -0,0 +1,452 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A fixed capacity priority queue.
+//!
+//! See [`ChunkHeap`](struct.ChunkHeap.html)
+
+use std::fmt::{Debug, Error, Formatter};
+use std::iter::FromIterator;
+use std::ops::{Deref, DerefMut};
+use std::slice::Iter as SliceIter;
+
+use typenum::U64;
+
+use crate::sized_chunk::{self, Chunk};
+use crate::types::ChunkLength;
+
+/// A fixed capacity priority queue.
+///
+/// A `ChunkHeap` is a binary max-heap stored in a [`Chunk`][Chunk], working
+/// like a `BinaryHeap` with a fixed capacity: [`pop`][pop] always returns the
+/// largest value in the heap.
+///
+/// When the heap is full, [`try_push`][try_push] hands back the value it
+/// couldn't push, and [`push_evict_min`][push_evict_min] makes room by
+/// discarding the smallest value, which lets you use a `ChunkHeap` to keep
+/// track of the largest `N` values you've seen.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sized_chunks::ChunkHeap;
+/// # use typenum::U3;
+/// let mut top: ChunkHeap<i32, U3> = ChunkHeap::new();
+/// for value in &[5, 1, 8, 3, 9, 2] {
+///     top.push_evict_min(*value);
+/// }
+/// assert_eq!(Some(&9), top.peek());
+/// assert_eq!(&[5, 8, 9], top.into_sorted_chunk().as_slice());
+/// ```
+///
+/// [Chunk]: ../sized_chunk/struct.Chunk.html
+/// [pop]: #method.pop
+/// [try_push]: #method.try_push
+/// [push_evict_min]: #method.push_evict_min
+pub struct ChunkHeap<A, N = U64>
+where
+    N: ChunkLength<A>,
+{
+    data: Chunk<A, N>,
+}
+
+impl<A, N> ChunkHeap<A, N>
+where
+    N: ChunkLength<A>,
+{
+    /// The maximum number of values this `ChunkHeap` can contain.
+    pub const CAPACITY: usize = N::USIZE;
+
+    /// Construct a new empty heap.
+    pub fn new() -> Self {
+        Self { data: Chunk::new() }
+    }
+
+    /// Get the number of values in the heap.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Test if the heap is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Test if the heap is at capacity.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.data.is_full()
+    }
+
+    /// Get the largest value in the heap.
+    ///
+    /// Time: O(1)
+    pub fn peek(&self) -> Option<&A> {
+        self.data.first()
+    }
+
+    /// Get the heap's values as a slice, in heap order.
+    pub fn as_slice(&self) -> &[A] {
+        &self.data
+    }
+
+    /// Get an iterator over the heap's values, in heap order.
+    pub fn iter(&self) -> SliceIter<'_, A> {
+        self.data.iter()
+    }
+
+    /// Discard the heap, returning its values in heap order.
+    pub fn into_chunk(self) -> Chunk<A, N> {
+        self.data
+    }
+
+    /// Discard all values from the heap.
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+}
+
+impl<A, N> ChunkHeap<A, N>
+where
+    A: Ord,
+    N: ChunkLength<A>,
+{
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.data[index] <= self.data[parent] {
+                break;
+            }
+            self.data.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize, len: usize) {
+        loop {
+            let mut child = 2 * index + 1;
+            if child >= len {
+                break;
+            }
+            if child + 1 < len && self.data[child + 1] > self.data[child] {
+                child += 1;
+            }
+            if self.data[index] >= self.data[child] {
+                break;
+            }
+            self.data.swap(index, child);
+            index = child;
+        }
+    }
+
+    /// Push a value onto the heap.
+    ///
+    /// Panics if the heap is full.
+    ///
+    /// Time: O(log n)
+    pub fn push(&mut self, value: A) {
+        if self.is_full() {
+            panic!("ChunkHeap::push: heap is full");
+        }
+        self.data.push_back(value);
+        self.sift_up(self.len() - 1);
+    }
+
+    /// Push a value onto the heap, or give it back if the heap is full.
+    ///
+    /// Time: O(log n)
+    pub fn try_push(&mut self, value: A) -> Result<(), A> {
+        if self.is_full() {
+            Err(value)
+        } else {
+            self.push(value);
+            Ok(())
+        }
+    }
+
+    /// Push a value onto the heap, making room for it by removing the
+    /// smallest value if the heap is full.
+    ///
+    /// Returns the value which was removed, which is `value` itself if it's
+    /// no larger than anything already in the heap. Returns `None` if the heap
+    /// wasn't full.
+    ///
+    /// Time: O(log n) if the heap isn't full, O(n) otherwise
+    pub fn push_evict_min(&mut self, value: A) -> Option<A> {
+        if !self.is_full() {
+            self.push(value);
+            return None;
+        }
+        // The smallest value is always one of the leaves.
+        let leaves = self.len() / 2..self.len();
+        let min = match leaves.min_by(|left, right| self.data[*left].cmp(&self.data[*right])) {
+            Some(min) => min,
+            None => return Some(value),
+        };
+        if value <= self.data[min] {
+            return Some(value);
+        }
+        let evicted = std::mem::replace(&mut self.data[min], value);
+        self.sift_up(min);
+        Some(evicted)
+    }
+
+    /// Remove the largest value from the heap and return it.
+    ///
+    /// Time: O(log n)
+    pub fn pop(&mut self) -> Option<A> {
+        if self.is_empty() {
+            return None;
+        }
+        let last = self.len() - 1;
+        self.data.swap(0, last);
+        let value = self.data.pop_back();
+        self.sift_down(0, self.len());
+        Some(value)
+    }
+
+    /// Get a mutable reference to the largest value in the heap.
+    ///
+    /// The heap is reordered when the returned [`PeekMut`][PeekMut] is
+    /// dropped, in case the value was changed.
+    ///
+    /// Time: O(1), and O(log n) when the `PeekMut` is dropped
+    ///
+    /// [PeekMut]: struct.PeekMut.html
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, A, N>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(PeekMut { heap: self })
+        }
+    }
+
+    /// Discard the heap, returning its values as a chunk sorted in ascending
+    /// order.
+    ///
+    /// Time: O(n log n)
+    pub fn into_sorted_chunk(mut self) -> Chunk<A, N> {
+        let mut end = self.len();
+        while end > 1 {
+            end -= 1;
+            self.data.swap(0, end);
+            self.sift_down(0, end);
+        }
+        self.data
+    }
+}
+
+/// A mutable reference to the largest value in a [`ChunkHeap`][ChunkHeap].
+///
+/// This is returned by [`ChunkHeap::peek_mut`][peek_mut].
+///
+/// [ChunkHeap]: struct.ChunkHeap.html
+/// [peek_mut]: struct.ChunkHeap.html#method.peek_mut
+pub struct PeekMut<'a, A, N>
+where
+    A: Ord,
+    N: ChunkLength<A>,
+{
+    heap: &'a mut ChunkHeap<A, N>,
+}
+
+impl<'a, A, N> PeekMut<'a, A, N>
+where
+    A: Ord,
+    N: ChunkLength<A>,
+{
+    /// Remove the peeked value from the heap and return it.
+    pub fn pop(this: Self) -> A {
+        let value = this.heap.pop();
+        std::mem::forget(this);
+        value.unwrap()
+    }
+}
+
+impl<'a, A, N> Drop for PeekMut<'a, A, N>
+where
+    A: Ord,
+    N: ChunkLength<A>,
+{
+    fn drop(&mut self) {
+        let len = self.heap.len();
+        self.heap.sift_down(0, len);
+    }
+}
+
+impl<'a, A, N> Deref for PeekMut<'a, A, N>
+where
+    A: Ord,
+    N: ChunkLength<A>,
+{
+    type Target = A;
+
+    fn deref(&self) -> &Self::Target {
+        &self.heap.data[0]
+    }
+}
+
+impl<'a, A, N> DerefMut for PeekMut<'a, A, N>
+where
+    A: Ord,
+    N: ChunkLength<A>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.heap.data[0]
+    }
+}
+
+impl<A, N> Default for ChunkHeap<A, N>
+where
+    N: ChunkLength<A>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A, N> Clone for ChunkHeap<A, N>
+where
+    A: Clone,
+    N: ChunkLength<A>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<A, N> Debug for ChunkHeap<A, N>
+where
+    A: Debug,
+    N: ChunkLength<A>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("ChunkHeap")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<A, N> From<Chunk<A, N>> for ChunkHeap<A, N>
+where
+    A: Ord,
+    N: ChunkLength<A>,
+{
+    /// Turn a chunk into a heap in place.
+    ///
+    /// Time: O(n)
+    fn from(data: Chunk<A, N>) -> Self {
+        let mut heap = Self { data };
+        let len = heap.len();
+        for index in (0..len / 2).rev() {
+            heap.sift_down(index, len);
+        }
+        heap
+    }
+}
+
+impl<A, N> FromIterator<A> for ChunkHeap<A, N>
+where
+    A: Ord,
+    N: ChunkLength<A>,
+{
+    fn from_iter<I>(it: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+    {
+        Chunk::from_iter(it).into()
+    }
+}
+
+impl<A, N> Extend<A> for ChunkHeap<A, N>
+where
+    A: Ord,
+    N: ChunkLength<A>,
+{
+    /// Push the contents of an iterator onto the heap.
+    ///
+    /// Panics if the heap exceeds its capacity.
+    fn extend<I>(&mut self, it: I)
+    where
+        I: IntoIterator<Item = A>,
+    {
+        for value in it {
+            self.push(value);
+        }
+    }
+}
+
+impl<A, N> IntoIterator for ChunkHeap<A, N>
+where
+    N: ChunkLength<A>,
+{
+    type Item = A;
+    type IntoIter = sized_chunk::Iter<A, N>;
+
+    /// Turn the heap into an iterator over its values in heap order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, A, N> IntoIterator for &'a ChunkHeap<A, N>
+where
+    N: ChunkLength<A>,
+{
+    type Item = &'a A;
+    type IntoIter = SliceIter<'a, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use typenum::{U4, U8};
+
+    #[test]
+    fn push_and_pop() {
+        let mut heap: ChunkHeap<i32, U8> = ChunkHeap::new();
+        for value in &[3, 7, 1, 8, 2, 9, 4] {
+            heap.push(*value);
+        }
+        assert_eq!(Some(&9), heap.peek());
+        let mut out = Vec::new();
+        while let Some(value) = heap.pop() {
+            out.push(value);
+        }
+        assert_eq!(vec![9, 8, 7, 4, 3, 2, 1], out);
+    }
+
+    #[test]
+    fn try_push_and_evict() {
+        let mut heap: ChunkHeap<i32, U4> = vec![4, 6, 2, 8].into_iter().collect();
+        assert_eq!(Err(5), heap.try_push(5));
+        assert_eq!(Some(1), heap.push_evict_min(1));
+        assert_eq!(Some(2), heap.push_evict_min(5));
+        assert_eq!(Some(4), heap.push_evict_min(10));
+        assert_eq!(&[5, 6, 8, 10], heap.into_sorted_chunk().as_slice());
+    }
+
+    #[test]
+    fn peek_mut_reorders() {
+        let mut heap: ChunkHeap<i32, U8> = (1..=5).collect();
+        *heap.peek_mut().unwrap() = 0;
+        assert_eq!(Some(&4), heap.peek());
+        assert_eq!(4, PeekMut::pop(heap.peek_mut().unwrap()));
+        assert_eq!(&[0, 1, 2, 3], heap.into_sorted_chunk().as_slice());
+    }
+
+    #[test]
+    #[should_panic(expected = "ChunkHeap::push: heap is full")]
+    fn push_to_full() {
+        let mut heap: ChunkHeap<i32, U4> = (0..4).collect();
+        heap.push(4);
+    }
+}
@@ -0,0 +1,694 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A fixed capacity sorted map.
+//!
+//! See [`ChunkMap`](struct.ChunkMap.html)
+
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Error, Formatter};
+use std::hash::{Hash, Hasher};
+use std::iter::{FromIterator, Zip};
+use std::ops::{Bound, Index, IndexMut, Range, RangeBounds};
+use std::slice::{Iter as SliceIter, IterMut as SliceIterMut};
+
+use typenum::U64;
+
+use crate::sized_chunk::{self, Chunk};
+use crate::types::ChunkLength;
+
+/// An iterator over the entries of a [`ChunkMap`][ChunkMap], in key order.
+///
+/// [ChunkMap]: struct.ChunkMap.html
+pub type Iter<'a, K, V> = Zip<SliceIter<'a, K>, SliceIter<'a, V>>;
+
+/// A mutable iterator over the entries of a [`ChunkMap`][ChunkMap], in key
+/// order.
+///
+/// [ChunkMap]: struct.ChunkMap.html
+pub type IterMut<'a, K, V> = Zip<SliceIter<'a, K>, SliceIterMut<'a, V>>;
+
+/// A consuming iterator over the entries of a [`ChunkMap`][ChunkMap], in key
+/// order.
+///
+/// [ChunkMap]: struct.ChunkMap.html
+pub type IntoIter<K, V, N> = Zip<sized_chunk::Iter<K, N>, sized_chunk::Iter<V, N>>;
+
+/// Find the indices of the keys in a sorted slice which fall inside `range`.
+pub(crate) fn range_indices<K, Q, R>(keys: &[K], range: &R, name: &str) -> Range<usize>
+where
+    K: Borrow<Q>,
+    Q: Ord + ?Sized,
+    R: RangeBounds<Q>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(key) => keys.partition_point(|k| k.borrow() < key),
+        Bound::Excluded(key) => keys.partition_point(|k| k.borrow() <= key),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(key) => keys.partition_point(|k| k.borrow() <= key),
+        Bound::Excluded(key) => keys.partition_point(|k| k.borrow() < key),
+        Bound::Unbounded => keys.len(),
+    };
+    if start > end {
+        panic!("{}: range start is greater than range end", name);
+    }
+    start..end
+}
+
+/// A fixed capacity sorted map.
+///
+/// A `ChunkMap` keeps its keys sorted in one [`Chunk`][Chunk] and their
+/// values in another, so lookups are a binary search over a contiguous slice
+/// of keys. Insertions and removals shift the entries following them, but
+/// since a [`Chunk`][Chunk] can grow in both directions, changes near the front
+/// of the map are as cheap as changes near the back.
+///
+/// It works like a `BTreeMap` with a fixed capacity, and is well suited as the
+/// leaf node of a B-tree.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sized_chunks::ChunkMap;
+/// # use typenum::U8;
+/// let mut map: ChunkMap<i32, &str, U8> = ChunkMap::new();
+/// map.insert(3, "three");
+/// map.insert(1, "one");
+/// map.insert(2, "two");
+/// assert_eq!(Some(&"two"), map.get(&2));
+/// assert_eq!(&[1, 2, 3], map.keys());
+/// let high = map.split_off(&2);
+/// assert_eq!(&[1], map.keys());
+/// assert_eq!(&[2, 3], high.keys());
+/// ```
+///
+/// [Chunk]: ../sized_chunk/struct.Chunk.html
+pub struct ChunkMap<K, V, N = U64>
+where
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    keys: Chunk<K, N>,
+    values: Chunk<V, N>,
+}
+
+impl<K, V, N> ChunkMap<K, V, N>
+where
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    /// The maximum number of entries this `ChunkMap` can contain.
+    pub const CAPACITY: usize = N::USIZE;
+
+    /// Construct a new empty map.
+    pub fn new() -> Self {
+        Self {
+            keys: Chunk::new(),
+            values: Chunk::new(),
+        }
+    }
+
+    /// Construct a map with a single entry.
+    pub fn unit(key: K, value: V) -> Self {
+        Self {
+            keys: Chunk::unit(key),
+            values: Chunk::unit(value),
+        }
+    }
+
+    /// Get the number of entries in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Test if the map is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Test if the map is at capacity.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.keys.is_full()
+    }
+
+    /// Get the map's keys as a sorted slice.
+    #[inline]
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    /// Get the map's values as a slice, in the order of their keys.
+    #[inline]
+    pub fn values(&self) -> &[V] {
+        &self.values
+    }
+
+    /// Get the map's values as a mutable slice, in the order of their keys.
+    #[inline]
+    pub fn values_mut(&mut self) -> &mut [V] {
+        &mut self.values
+    }
+
+    /// Get an iterator over the map's entries, in key order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.keys.iter().zip(self.values.iter())
+    }
+
+    /// Get an iterator over the map's entries with mutable values, in key
+    /// order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        self.keys.iter().zip(self.values.iter_mut())
+    }
+
+    /// Get the entry with the smallest key.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        Some((self.keys.first()?, self.values.first()?))
+    }
+
+    /// Get the entry with the largest key.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        Some((self.keys.last()?, self.values.last()?))
+    }
+
+    /// Remove and return the entry with the smallest key.
+    ///
+    /// Time: O(1)
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        if self.is_empty() {
+            None
+        } else {
+            Some((self.keys.pop_front(), self.values.pop_front()))
+        }
+    }
+
+    /// Remove and return the entry with the largest key.
+    ///
+    /// Time: O(1)
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        if self.is_empty() {
+            None
+        } else {
+            Some((self.keys.pop_back(), self.values.pop_back()))
+        }
+    }
+
+    /// Discard all entries from the map.
+    pub fn clear(&mut self) {
+        self.keys.clear();
+        self.values.clear();
+    }
+
+    /// Discard the map's values and return the chunk of its keys.
+    pub(crate) fn into_keys(self) -> Chunk<K, N> {
+        self.keys
+    }
+}
+
+impl<K, V, N> ChunkMap<K, V, N>
+where
+    K: Ord,
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    /// Find the index of a key in the map's sorted [`keys()`][keys].
+    ///
+    /// Returns `Ok` with the index of the key if it's present, or `Err` with
+    /// the index where it would be inserted if it isn't, like
+    /// `slice::binary_search`.
+    ///
+    /// Time: O(log n)
+    ///
+    /// [keys]: #method.keys
+    pub fn search<Q>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.keys.binary_search_by(|k| k.borrow().cmp(key))
+    }
+
+    /// Test if the map contains a key.
+    ///
+    /// Time: O(log n)
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.search(key).is_ok()
+    }
+
+    /// Look up the value for a key.
+    ///
+    /// Time: O(log n)
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+        Some(&self.values[index])
+    }
+
+    /// Look up a mutable reference to the value for a key.
+    ///
+    /// Time: O(log n)
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+        Some(&mut self.values[index])
+    }
+
+    /// Look up the stored key and value for a key.
+    ///
+    /// Time: O(log n)
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+        Some((&self.keys[index], &self.values[index]))
+    }
+
+    /// Insert a key and value into the map.
+    ///
+    /// If the key was already present, its value is replaced and the old
+    /// value returned, and the key itself isn't updated.
+    ///
+    /// Panics if the key isn't present and the map is full.
+    ///
+    /// Time: O(n) for the number of entries on the shorter side of the key
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.search(&key) {
+            Ok(index) => Some(std::mem::replace(&mut self.values[index], value)),
+            Err(index) => {
+                if self.is_full() {
+                    panic!("ChunkMap::insert: map is full");
+                }
+                self.keys.insert(index, key);
+                self.values.insert(index, value);
+                None
+            }
+        }
+    }
+
+    /// Remove a key from the map, returning its value.
+    ///
+    /// Time: O(n) for the number of entries on the shorter side of the key
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Remove a key from the map, returning the stored key and its value.
+    ///
+    /// Time: O(n) for the number of entries on the shorter side of the key
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+        Some((self.keys.remove(index), self.values.remove(index)))
+    }
+
+    /// Get an iterator over the entries whose keys fall inside `range`, in key
+    /// order.
+    ///
+    /// Panics if the start of the range is greater than its end.
+    ///
+    /// Time: O(log n)
+    pub fn range<Q, R>(&self, range: R) -> Iter<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let range = range_indices(&self.keys, &range, "ChunkMap::range");
+        self.keys[range.clone()]
+            .iter()
+            .zip(self.values[range].iter())
+    }
+
+    /// Get an iterator over the entries whose keys fall inside `range`, with
+    /// mutable values, in key order.
+    ///
+    /// Panics if the start of the range is greater than its end.
+    ///
+    /// Time: O(log n)
+    pub fn range_mut<Q, R>(&mut self, range: R) -> IterMut<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let range = range_indices(&self.keys, &range, "ChunkMap::range_mut");
+        self.keys[range.clone()]
+            .iter()
+            .zip(self.values[range].iter_mut())
+    }
+
+    /// Split the map in two at `key`, leaving the entries with keys less than
+    /// `key` in this map and returning a new map with the rest.
+    ///
+    /// Time: O(n) for the number of entries in the new map
+    pub fn split_off<Q>(&mut self, key: &Q) -> Self
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = match self.search(key) {
+            Ok(index) | Err(index) => index,
+        };
+        Self {
+            keys: self.keys.split_off(index),
+            values: self.values.split_off(index),
+        }
+    }
+
+    /// Merge the entries of another map into this one, leaving `other` empty.
+    ///
+    /// When both maps contain the same key, the value from `other` replaces
+    /// the value in this map, like `BTreeMap::append`.
+    ///
+    /// Panics if the merged map would exceed the capacity, in which case
+    /// neither map is changed.
+    ///
+    /// Time: O(n+m)
+    pub fn merge(&mut self, other: &mut Self) {
+        let mut merged_len = self.len() + other.len();
+        let (mut left, mut right) = (0, 0);
+        while left < self.len() && right < other.len() {
+            match self.keys[left].cmp(&other.keys[right]) {
+                Ordering::Less => left += 1,
+                Ordering::Greater => right += 1,
+                Ordering::Equal => {
+                    merged_len -= 1;
+                    left += 1;
+                    right += 1;
+                }
+            }
+        }
+        if merged_len > Self::CAPACITY {
+            panic!("ChunkMap::merge: merged map exceeds capacity");
+        }
+        if other.is_empty() {
+            return;
+        }
+        let mut keys = Chunk::new();
+        let mut values = Chunk::new();
+        while !self.is_empty() && !other.is_empty() {
+            let (key, value) = match self.keys[0].cmp(&other.keys[0]) {
+                Ordering::Less => (self.keys.pop_front(), self.values.pop_front()),
+                Ordering::Greater => (other.keys.pop_front(), other.values.pop_front()),
+                Ordering::Equal => {
+                    self.keys.pop_front();
+                    self.values.pop_front();
+                    (other.keys.pop_front(), other.values.pop_front())
+                }
+            };
+            keys.push_back(key);
+            values.push_back(value);
+        }
+        keys.append(&mut self.keys);
+        keys.append(&mut other.keys);
+        values.append(&mut self.values);
+        values.append(&mut other.values);
+        self.keys = keys;
+        self.values = values;
+    }
+}
+
+impl<K, V, N> Default for ChunkMap<K, V, N>
+where
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, N> Clone for ChunkMap<K, V, N>
+where
+    K: Clone,
+    V: Clone,
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            keys: self.keys.clone(),
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<'a, K, V, N, Q> Index<&'a Q> for ChunkMap<K, V, N>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    type Output = V;
+
+    fn index(&self, key: &'a Q) -> &Self::Output {
+        self.get(key).expect("ChunkMap::index: key not found")
+    }
+}
+
+impl<'a, K, V, N, Q> IndexMut<&'a Q> for ChunkMap<K, V, N>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    fn index_mut(&mut self, key: &'a Q) -> &mut Self::Output {
+        self.get_mut(key)
+            .expect("ChunkMap::index_mut: key not found")
+    }
+}
+
+impl<K, V, N> PartialEq for ChunkMap<K, V, N>
+where
+    K: PartialEq,
+    V: PartialEq,
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.keys == other.keys && self.values == other.values
+    }
+}
+
+impl<K, V, N> Eq for ChunkMap<K, V, N>
+where
+    K: Eq,
+    V: Eq,
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+}
+
+impl<K, V, N> PartialOrd for ChunkMap<K, V, N>
+where
+    K: PartialOrd,
+    V: PartialOrd,
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<K, V, N> Ord for ChunkMap<K, V, N>
+where
+    K: Ord,
+    V: Ord,
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<K, V, N> Hash for ChunkMap<K, V, N>
+where
+    K: Hash,
+    V: Hash,
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        hasher.write_usize(self.len());
+        for entry in self.iter() {
+            entry.hash(hasher);
+        }
+    }
+}
+
+impl<K, V, N> Debug for ChunkMap<K, V, N>
+where
+    K: Debug,
+    V: Debug,
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("ChunkMap")?;
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, N> FromIterator<(K, V)> for ChunkMap<K, V, N>
+where
+    K: Ord,
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    fn from_iter<I>(it: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut map = Self::new();
+        map.extend(it);
+        map
+    }
+}
+
+impl<K, V, N> Extend<(K, V)> for ChunkMap<K, V, N>
+where
+    K: Ord,
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    /// Insert the contents of an iterator into the map.
+    ///
+    /// Panics if the map exceeds its capacity.
+    fn extend<I>(&mut self, it: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        for (key, value) in it {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V, N> IntoIterator for ChunkMap<K, V, N>
+where
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.keys.into_iter().zip(self.values)
+    }
+}
+
+impl<'a, K, V, N> IntoIterator for &'a ChunkMap<K, V, N>
+where
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, N> IntoIterator for &'a mut ChunkMap<K, V, N>
+where
+    N: ChunkLength<K> + ChunkLength<V>,
+{
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use typenum::U8;
+
+    #[test]
+    fn insert_and_lookup() {
+        let mut map: ChunkMap<i32, i32, U8> = ChunkMap::new();
+        for key in &[5, 1, 7, 3] {
+            assert_eq!(None, map.insert(*key, key * 10));
+        }
+        assert_eq!(Some(30), map.insert(3, 33));
+        assert_eq!(&[1, 3, 5, 7], map.keys());
+        assert_eq!(&[10, 33, 50, 70], map.values());
+        assert_eq!(Some(&50), map.get(&5));
+        assert_eq!(None, map.get(&4));
+        assert_eq!(Err(2), map.search(&4));
+        map[&7] += 1;
+        assert_eq!(Some(71), map.remove(&7));
+        assert_eq!(Some((1, 10)), map.pop_first());
+        assert_eq!(Some((&5, &50)), map.last());
+    }
+
+    #[test]
+    #[should_panic(expected = "ChunkMap::insert: map is full")]
+    fn insert_into_full() {
+        let mut map: ChunkMap<usize, (), U8> = (0..8).map(|key| (key, ())).collect();
+        map.insert(3, ());
+        map.insert(8, ());
+    }
+
+    #[test]
+    fn range() {
+        let map: ChunkMap<i32, char, U8> = (0..8).zip("abcdefgh".chars()).collect();
+        let values = |iter: Iter<'_, i32, char>| iter.map(|(_, v)| *v).collect::<String>();
+        assert_eq!("cde", values(map.range(2..5)));
+        assert_eq!("cdef", values(map.range(2..=5)));
+        assert_eq!("ab", values(map.range(..2)));
+        assert_eq!("gh", values(map.range(6..)));
+        assert_eq!("", values(map.range(10..)));
+        assert_eq!(
+            "de",
+            values(map.range((Bound::Excluded(2), Bound::Excluded(5))))
+        );
+    }
+
+    #[test]
+    fn split_off_and_merge() {
+        let mut map: ChunkMap<i32, i32, U8> = (0..6).map(|key| (key * 2, key)).collect();
+        let mut high = map.split_off(&5);
+        assert_eq!(&[0, 2, 4], map.keys());
+        assert_eq!(&[6, 8, 10], high.keys());
+        let mut other: ChunkMap<i32, i32, U8> =
+            vec![(1, -1), (4, -4), (8, -8)].into_iter().collect();
+        map.merge(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(&[0, 1, 2, 4, 8], map.keys());
+        assert_eq!(&[0, -1, 1, -4, -8], map.values());
+        map.merge(&mut high);
+        assert_eq!(&[0, 1, 2, 4, 6, 8, 10], map.keys());
+        assert_eq!(&[0, -1, 1, -4, 3, 4, 5], map.values());
+    }
+
+    #[test]
+    fn merge_overflow_leaves_maps_intact() {
+        let mut map: ChunkMap<i32, (), U8> = (0..5).map(|key| (key, ())).collect();
+        let mut other: ChunkMap<i32, (), U8> = (3..8).map(|key| (key, ())).collect();
+        map.merge(&mut other);
+        assert_eq!(8, map.len());
+        let mut map: ChunkMap<i32, (), U8> = (0..5).map(|key| (key, ())).collect();
+        let mut other: ChunkMap<i32, (), U8> = (10..14).map(|key| (key, ())).collect();
+        let result =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| map.merge(&mut other)));
+        assert!(result.is_err());
+        assert_eq!(5, map.len());
+        assert_eq!(4, other.len());
+    }
+}
@@ -0,0 +1,430 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A fixed capacity sorted set.
+//!
+//! See [`ChunkSet`](struct.ChunkSet.html)
+
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Error, Formatter};
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::ops::RangeBounds;
+use std::slice::Iter as SliceIter;
+
+use typenum::U64;
+
+use crate::chunk_map::{range_indices, ChunkMap};
+use crate::sized_chunk;
+use crate::types::ChunkLength;
+
+/// A fixed capacity sorted set.
+///
+/// A `ChunkSet` is a [`ChunkMap`][ChunkMap] with `()` for its values, the way
+/// `HashSet` wraps a `HashMap`, so lookups are a binary search over a
+/// contiguous slice of its values, and it works like a `BTreeSet` with a
+/// fixed capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sized_chunks::ChunkSet;
+/// # use typenum::U8;
+/// let mut set: ChunkSet<i32, U8> = vec![5, 3, 1].into_iter().collect();
+/// assert!(set.insert(4));
+/// assert!(!set.insert(3));
+/// assert_eq!(&[1, 3, 4, 5], set.as_slice());
+/// assert_eq!(vec![&3, &4], set.range(2..5).collect::<Vec<_>>());
+/// ```
+///
+/// [ChunkMap]: ../chunk_map/struct.ChunkMap.html
+pub struct ChunkSet<K, N = U64>
+where
+    N: ChunkLength<K> + ChunkLength<()>,
+{
+    map: ChunkMap<K, (), N>,
+}
+
+impl<K, N> ChunkSet<K, N>
+where
+    N: ChunkLength<K> + ChunkLength<()>,
+{
+    /// The maximum number of values this `ChunkSet` can contain.
+    pub const CAPACITY: usize = N::USIZE;
+
+    /// Construct a new empty set.
+    pub fn new() -> Self {
+        Self {
+            map: ChunkMap::new(),
+        }
+    }
+
+    /// Construct a set with a single value.
+    pub fn unit(value: K) -> Self {
+        Self {
+            map: ChunkMap::unit(value, ()),
+        }
+    }
+
+    /// Get the number of values in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Test if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Test if the set is at capacity.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Get the set's values as a sorted slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[K] {
+        self.map.keys()
+    }
+
+    /// Get an iterator over the set's values, in order.
+    pub fn iter(&self) -> SliceIter<'_, K> {
+        self.as_slice().iter()
+    }
+
+    /// Get the smallest value in the set.
+    pub fn first(&self) -> Option<&K> {
+        self.map.first().map(|(value, _)| value)
+    }
+
+    /// Get the largest value in the set.
+    pub fn last(&self) -> Option<&K> {
+        self.map.last().map(|(value, _)| value)
+    }
+
+    /// Remove and return the smallest value in the set.
+    ///
+    /// Time: O(1)
+    pub fn pop_first(&mut self) -> Option<K> {
+        self.map.pop_first().map(|(value, _)| value)
+    }
+
+    /// Remove and return the largest value in the set.
+    ///
+    /// Time: O(1)
+    pub fn pop_last(&mut self) -> Option<K> {
+        self.map.pop_last().map(|(value, _)| value)
+    }
+
+    /// Discard all values from the set.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+}
+
+impl<K, N> ChunkSet<K, N>
+where
+    K: Ord,
+    N: ChunkLength<K> + ChunkLength<()>,
+{
+    /// Find the index of a value in the set's sorted [`as_slice()`][as_slice].
+    ///
+    /// Returns `Ok` with the index of the value if it's present, or `Err` with
+    /// the index where it would be inserted if it isn't, like
+    /// `slice::binary_search`.
+    ///
+    /// Time: O(log n)
+    ///
+    /// [as_slice]: #method.as_slice
+    pub fn search<Q>(&self, value: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.search(value)
+    }
+
+    /// Test if the set contains a value.
+    ///
+    /// Time: O(log n)
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Get a reference to the stored value equal to `value`.
+    ///
+    /// Time: O(log n)
+    pub fn get<Q>(&self, value: &Q) -> Option<&K>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.get_key_value(value).map(|(value, _)| value)
+    }
+
+    /// Insert a value into the set.
+    ///
+    /// Returns `false` and leaves the set unchanged if the value was already
+    /// present.
+    ///
+    /// Panics if the value isn't present and the set is full.
+    ///
+    /// Time: O(n) for the number of values on the shorter side of the new
+    /// value
+    pub fn insert(&mut self, value: K) -> bool {
+        self.map.insert(value, ()).is_none()
+    }
+
+    /// Remove a value from the set, returning `true` if it was present.
+    ///
+    /// Time: O(n) for the number of values on the shorter side of the value
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.remove(value).is_some()
+    }
+
+    /// Remove a value from the set, returning the stored value.
+    ///
+    /// Time: O(n) for the number of values on the shorter side of the value
+    pub fn take<Q>(&mut self, value: &Q) -> Option<K>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.map.remove_entry(value).map(|(value, _)| value)
+    }
+
+    /// Get an iterator over the values which fall inside `range`, in order.
+    ///
+    /// Panics if the start of the range is greater than its end.
+    ///
+    /// Time: O(log n)
+    pub fn range<Q, R>(&self, range: R) -> SliceIter<'_, K>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let range = range_indices(self.as_slice(), &range, "ChunkSet::range");
+        self.as_slice()[range].iter()
+    }
+
+    /// Split the set in two at `value`, leaving the values less than `value`
+    /// in this set and returning a new set with the rest.
+    ///
+    /// Time: O(n) for the number of values in the new set
+    pub fn split_off<Q>(&mut self, value: &Q) -> Self
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        Self {
+            map: self.map.split_off(value),
+        }
+    }
+
+    /// Merge the values of another set into this one, leaving `other` empty.
+    ///
+    /// When both sets contain the same value, the one from `other` is kept,
+    /// like `BTreeSet::append`.
+    ///
+    /// Panics if the merged set would exceed the capacity, in which case
+    /// neither set is changed.
+    ///
+    /// Time: O(n+m)
+    pub fn merge(&mut self, other: &mut Self) {
+        self.map.merge(&mut other.map)
+    }
+}
+
+impl<K, N> Default for ChunkSet<K, N>
+where
+    N: ChunkLength<K> + ChunkLength<()>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, N> Clone for ChunkSet<K, N>
+where
+    K: Clone,
+    N: ChunkLength<K> + ChunkLength<()>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<K, N> PartialEq for ChunkSet<K, N>
+where
+    K: PartialEq,
+    N: ChunkLength<K> + ChunkLength<()>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<K, N> Eq for ChunkSet<K, N>
+where
+    K: Eq,
+    N: ChunkLength<K> + ChunkLength<()>,
+{
+}
+
+impl<K, N> PartialOrd for ChunkSet<K, N>
+where
+    K: PartialOrd,
+    N: ChunkLength<K> + ChunkLength<()>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<K, N> Ord for ChunkSet<K, N>
+where
+    K: Ord,
+    N: ChunkLength<K> + ChunkLength<()>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<K, N> Hash for ChunkSet<K, N>
+where
+    K: Hash,
+    N: ChunkLength<K> + ChunkLength<()>,
+{
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.as_slice().hash(hasher)
+    }
+}
+
+impl<K, N> Debug for ChunkSet<K, N>
+where
+    K: Debug,
+    N: ChunkLength<K> + ChunkLength<()>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("ChunkSet")?;
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<K, N> FromIterator<K> for ChunkSet<K, N>
+where
+    K: Ord,
+    N: ChunkLength<K> + ChunkLength<()>,
+{
+    fn from_iter<I>(it: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+    {
+        let mut set = Self::new();
+        set.extend(it);
+        set
+    }
+}
+
+impl<K, N> Extend<K> for ChunkSet<K, N>
+where
+    K: Ord,
+    N: ChunkLength<K> + ChunkLength<()>,
+{
+    /// Insert the contents of an iterator into the set.
+    ///
+    /// Panics if the set exceeds its capacity.
+    fn extend<I>(&mut self, it: I)
+    where
+        I: IntoIterator<Item = K>,
+    {
+        for value in it {
+            self.insert(value);
+        }
+    }
+}
+
+impl<K, N> IntoIterator for ChunkSet<K, N>
+where
+    N: ChunkLength<K> + ChunkLength<()>,
+{
+    type Item = K;
+    type IntoIter = sized_chunk::Iter<K, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_keys().into_iter()
+    }
+}
+
+impl<'a, K, N> IntoIterator for &'a ChunkSet<K, N>
+where
+    N: ChunkLength<K> + ChunkLength<()>,
+{
+    type Item = &'a K;
+    type IntoIter = SliceIter<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use typenum::U8;
+
+    #[test]
+    fn insert_and_remove() {
+        let mut set: ChunkSet<i32, U8> = ChunkSet::new();
+        for value in &[5, 1, 7, 3] {
+            assert!(set.insert(*value));
+        }
+        assert!(!set.insert(3));
+        assert_eq!(&[1, 3, 5, 7], set.as_slice());
+        assert!(set.contains(&5));
+        assert!(set.remove(&5));
+        assert!(!set.remove(&5));
+        assert_eq!(Some(1), set.pop_first());
+        assert_eq!(Some(7), set.pop_last());
+        assert_eq!(&[3], set.as_slice());
+    }
+
+    #[test]
+    fn range_split_and_merge() {
+        let mut set: ChunkSet<i32, U8> = (0..6).map(|value| value * 2).collect();
+        assert_eq!(vec![&4, &6], set.range(3..=6).collect::<Vec<_>>());
+        let mut high = set.split_off(&5);
+        assert_eq!(&[0, 2, 4], set.as_slice());
+        assert_eq!(&[6, 8, 10], high.as_slice());
+        let mut other: ChunkSet<i32, U8> = vec![1, 4, 8].into_iter().collect();
+        set.merge(&mut other);
+        set.merge(&mut high);
+        assert!(other.is_empty() && high.is_empty());
+        assert_eq!(&[0, 1, 2, 4, 6, 8, 10], set.as_slice());
+    }
+
+    #[test]
+    #[should_panic(expected = "ChunkMap::merge: merged map exceeds capacity")]
+    fn merge_overflow() {
+        let mut set: ChunkSet<i32, U8> = (0..5).collect();
+        let mut other: ChunkSet<i32, U8> = (10..14).collect();
+        set.merge(&mut other);
+    }
+}
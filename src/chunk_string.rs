@@ -0,0 +1,515 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A fixed capacity UTF-8 string.
+//!
+//! See [`ChunkString`](struct.ChunkString.html)
+
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
+use std::str::{from_utf8, from_utf8_unchecked, from_utf8_unchecked_mut, Utf8Error};
+
+use typenum::U64;
+
+use crate::sized_chunk::Chunk;
+use crate::types::ChunkLength;
+
+/// The error returned when a [`ChunkString`][ChunkString] doesn't have room
+/// for the text being added to it.
+///
+/// [ChunkString]: struct.ChunkString.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CapacityError;
+
+impl Display for CapacityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("insufficient capacity")
+    }
+}
+
+impl std::error::Error for CapacityError {}
+
+/// A fixed capacity string.
+///
+/// A `ChunkString` is a [`Chunk`][Chunk] of bytes which is guaranteed to
+/// contain valid UTF-8, and which dereferences to a `str`. Its capacity is
+/// measured in bytes, not chars.
+///
+/// Like `Chunk`, it can grow in both directions, so prefixing a string using
+/// [`push_front_str`][push_front_str] doesn't have to move the rest of it as
+/// long as there's room at the front of the chunk.
+///
+/// Methods which add text to the string return a [`CapacityError`][CapacityError],
+/// leaving the string unchanged, if there isn't room for all of it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sized_chunks::ChunkString;
+/// # use std::fmt::Write;
+/// # use typenum::U16;
+/// let mut string: ChunkString<U16> = ChunkString::new();
+/// string.push_str("world")?;
+/// string.push_front_str("hello ")?;
+/// write!(string, "{}", '!').unwrap();
+/// assert_eq!("hello world!", string);
+/// assert!(string.push_str(", and goodbye").is_err());
+/// # Ok::<(), sized_chunks::chunk_string::CapacityError>(())
+/// ```
+///
+/// [Chunk]: ../sized_chunk/struct.Chunk.html
+/// [CapacityError]: struct.CapacityError.html
+/// [push_front_str]: #method.push_front_str
+pub struct ChunkString<N = U64>
+where
+    N: ChunkLength<u8>,
+{
+    bytes: Chunk<u8, N>,
+}
+
+impl<N> ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    /// The maximum number of bytes this `ChunkString` can contain.
+    pub const CAPACITY: usize = N::USIZE;
+
+    /// Construct a new empty string.
+    pub fn new() -> Self {
+        Self {
+            bytes: Chunk::new(),
+        }
+    }
+
+    /// Construct a string from a chunk of bytes, if they're valid UTF-8.
+    pub fn from_utf8(bytes: Chunk<u8, N>) -> Result<Self, Utf8Error> {
+        from_utf8(&bytes)?;
+        Ok(Self { bytes })
+    }
+
+    /// Construct a string from a chunk of bytes without checking that they
+    /// are valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The bytes must be valid UTF-8.
+    pub unsafe fn from_utf8_unchecked(bytes: Chunk<u8, N>) -> Self {
+        Self { bytes }
+    }
+
+    /// Get the length of the string in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Test if the string is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Test if the string is at capacity.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.bytes.is_full()
+    }
+
+    /// Get the number of bytes which can still be added to the string.
+    #[inline]
+    pub fn remaining_capacity(&self) -> usize {
+        Self::CAPACITY - self.len()
+    }
+
+    /// Get the string as a `str`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        unsafe { from_utf8_unchecked(&self.bytes) }
+    }
+
+    /// Get the string as a mutable `str`.
+    #[inline]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        unsafe { from_utf8_unchecked_mut(&mut self.bytes) }
+    }
+
+    /// Get the string's bytes.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Discard the string and return the chunk holding its bytes.
+    pub fn into_bytes(self) -> Chunk<u8, N> {
+        self.bytes
+    }
+
+    fn check_capacity(&self, extra: usize) -> Result<(), CapacityError> {
+        if extra > self.remaining_capacity() {
+            Err(CapacityError)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Append a char to the end of the string.
+    ///
+    /// Time: O(1) if there's room at the back, O(n) otherwise
+    pub fn push(&mut self, ch: char) -> Result<(), CapacityError> {
+        self.push_str(ch.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Append a string slice to the end of the string.
+    ///
+    /// Time: O(m) if there's room at the back, O(m+n) otherwise, where m is
+    /// the length of the added text and n is the length of the string
+    pub fn push_str(&mut self, string: &str) -> Result<(), CapacityError> {
+        self.check_capacity(string.len())?;
+        self.bytes.extend(string.bytes());
+        Ok(())
+    }
+
+    /// Prepend a char to the start of the string.
+    ///
+    /// Time: O(1) if there's room at the front, O(n) otherwise
+    pub fn push_front(&mut self, ch: char) -> Result<(), CapacityError> {
+        self.push_front_str(ch.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Prepend a string slice to the start of the string.
+    ///
+    /// If there isn't room at the front of the underlying chunk, the string
+    /// is moved to the back of it first, so that subsequent prefixes will be
+    /// cheap.
+    ///
+    /// Time: O(m) if there's room at the front, O(m+n) otherwise, where m is
+    /// the length of the added text and n is the length of the string
+    pub fn push_front_str(&mut self, string: &str) -> Result<(), CapacityError> {
+        self.check_capacity(string.len())?;
+        if self.bytes.left_capacity() < string.len() {
+            self.bytes.align_right();
+        }
+        self.bytes.insert_from(0, string.bytes());
+        Ok(())
+    }
+
+    /// Insert a char at byte index `index`.
+    ///
+    /// Panics if `index` is out of bounds or isn't on a char boundary.
+    ///
+    /// Time: O(n) for the number of bytes on the shorter side of `index`, if
+    /// there's room on that side
+    pub fn insert(&mut self, index: usize, ch: char) -> Result<(), CapacityError> {
+        self.insert_str(index, ch.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Insert a string slice at byte index `index`.
+    ///
+    /// Panics if `index` is out of bounds or isn't on a char boundary.
+    ///
+    /// Time: O(m+n) where m is the length of the inserted text and n is the
+    /// number of bytes on the shorter side of `index`, if there's room on
+    /// that side
+    pub fn insert_str(&mut self, index: usize, string: &str) -> Result<(), CapacityError> {
+        if !self.as_str().is_char_boundary(index) {
+            panic!("ChunkString::insert_str: index is not a char boundary");
+        }
+        self.check_capacity(string.len())?;
+        self.bytes.insert_from(index, string.bytes());
+        Ok(())
+    }
+
+    /// Remove the last char from the string and return it.
+    ///
+    /// Time: O(1)
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next_back()?;
+        self.bytes.drop_right(self.len() - ch.len_utf8());
+        Some(ch)
+    }
+
+    /// Remove the first char from the string and return it.
+    ///
+    /// Time: O(1)
+    pub fn pop_front(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next()?;
+        self.bytes.drop_left(ch.len_utf8());
+        Some(ch)
+    }
+
+    /// Shorten the string to `len` bytes.
+    ///
+    /// Does nothing if the string is already shorter than `len`.
+    ///
+    /// Panics if `len` isn't on a char boundary.
+    ///
+    /// Time: O(1)
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            if !self.as_str().is_char_boundary(len) {
+                panic!("ChunkString::truncate: index is not a char boundary");
+            }
+            self.bytes.drop_right(len);
+        }
+    }
+
+    /// Discard the contents of the string.
+    ///
+    /// Time: O(1)
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+    }
+}
+
+impl<N> Default for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N> Clone for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+        }
+    }
+}
+
+impl<N> Deref for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<N> DerefMut for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_str()
+    }
+}
+
+impl<N> AsRef<str> for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<N> AsRef<[u8]> for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<N> Borrow<str> for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a, N> TryFrom<&'a str> for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    type Error = CapacityError;
+
+    fn try_from(string: &'a str) -> Result<Self, Self::Error> {
+        let mut out = Self::new();
+        out.push_str(string)?;
+        Ok(out)
+    }
+}
+
+impl<N> fmt::Write for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.push_str(string).map_err(|_| fmt::Error)
+    }
+
+    fn write_char(&mut self, ch: char) -> fmt::Result {
+        self.push(ch).map_err(|_| fmt::Error)
+    }
+}
+
+impl<N> Debug for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<N> Display for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<N, M> PartialEq<ChunkString<M>> for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+    M: ChunkLength<u8>,
+{
+    fn eq(&self, other: &ChunkString<M>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<N> PartialEq<str> for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<N> PartialEq<&str> for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<N> PartialEq<ChunkString<N>> for str
+where
+    N: ChunkLength<u8>,
+{
+    fn eq(&self, other: &ChunkString<N>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<N> PartialEq<ChunkString<N>> for &str
+where
+    N: ChunkLength<u8>,
+{
+    fn eq(&self, other: &ChunkString<N>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl<N> Eq for ChunkString<N> where N: ChunkLength<u8> {}
+
+impl<N> PartialOrd for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+/// Hashes the same as the equivalent `str`, as required by the
+/// `Borrow<str>` implementation.
+impl<N> Hash for ChunkString<N>
+where
+    N: ChunkLength<u8>,
+{
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.as_str().hash(hasher)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fmt::Write;
+    use typenum::U8;
+
+    #[test]
+    fn push_and_pop() {
+        let mut string: ChunkString<U8> = ChunkString::new();
+        string.push_str("bcd").unwrap();
+        string.push_front('a').unwrap();
+        string.push('é').unwrap();
+        assert_eq!("abcdé", string);
+        assert_eq!(2, string.remaining_capacity());
+        assert_eq!(Err(CapacityError), string.push_str("xyz"));
+        assert_eq!(Err(CapacityError), string.push_front_str("xyz"));
+        assert_eq!("abcdé", string);
+        assert_eq!(Some('é'), string.pop());
+        assert_eq!(Some('a'), string.pop_front());
+        assert_eq!("bcd", string);
+        string.truncate(1);
+        assert_eq!("b", string);
+    }
+
+    #[test]
+    fn prefix_uses_front_space() {
+        let mut string: ChunkString<U8> = ChunkString::try_from("def").unwrap();
+        string.push_front_str("c").unwrap();
+        assert_eq!(4, string.bytes.left_capacity());
+        string.push_front_str("ab").unwrap();
+        assert_eq!(2, string.bytes.left_capacity());
+        assert_eq!("abcdef", string);
+    }
+
+    #[test]
+    fn insert_and_write() {
+        let mut string: ChunkString<U8> = ChunkString::new();
+        write!(string, "{}-{}", 1, 3).unwrap();
+        string.insert_str(2, "2-").unwrap();
+        string.insert(0, '0').unwrap();
+        assert_eq!("01-2-3", string);
+        assert!(write!(string, "{}", 456).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "not a char boundary")]
+    fn insert_inside_char() {
+        let mut string: ChunkString<U8> = ChunkString::try_from("é").unwrap();
+        let _ = string.insert(1, 'x');
+    }
+
+    #[test]
+    fn from_utf8() {
+        let bytes: Chunk<u8, U8> = Chunk::from([0xff]);
+        assert!(ChunkString::from_utf8(bytes).is_err());
+        let bytes: Chunk<u8, U8> = "ok".bytes().collect();
+        assert_eq!("ok", ChunkString::from_utf8(bytes).unwrap());
+    }
+}
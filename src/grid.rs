@@ -0,0 +1,486 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A fixed size two dimensional grid.
+//!
+//! See [`Grid`](struct.Grid.html)
+
+use std::convert::TryFrom;
+use std::fmt::{Debug, Error, Formatter};
+use std::hash::{Hash, Hasher};
+use std::iter::{FusedIterator, StepBy};
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut, Index, IndexMut, Mul};
+use std::ptr;
+use std::slice::{ChunksExact, ChunksExactMut, Iter as SliceIter, IterMut as SliceIterMut};
+
+use typenum::{NonZero, Prod, Unsigned};
+
+use crate::sized_chunk::Chunk;
+use crate::types::ChunkLength;
+
+/// An iterator over the values in a column of a [`Grid`][Grid].
+///
+/// [Grid]: struct.Grid.html
+pub type Column<'a, A> = StepBy<SliceIter<'a, A>>;
+
+/// An iterator over mutable references to the values in a column of a
+/// [`Grid`][Grid].
+///
+/// [Grid]: struct.Grid.html
+pub type ColumnMut<'a, A> = StepBy<SliceIterMut<'a, A>>;
+
+/// A fixed size two dimensional grid.
+///
+/// A `Grid<A, R, C>` is a grid of `R` rows by `C` columns of values of type
+/// `A`, where `R` and `C` are [`Unsigned`][Unsigned] type level numerals. It's
+/// stored as a [`Chunk`][Chunk] of size `R * C`, row by row, and it's always
+/// full: every position in the grid always holds a value.
+///
+/// A grid dereferences to a slice of all its values in row order, and you can
+/// index it with a `(row, column)` pair.
+///
+/// The number of columns `C` must not be zero, which is enforced by a
+/// [`NonZero`][NonZero] bound. A grid may have zero rows, in which case every
+/// row and column is empty.
+///
+/// ```compile_fail
+/// # use sized_chunks::Grid;
+/// # use typenum::{U0, U2};
+/// let grid: Grid<usize, U2, U0> = Grid::default();
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// # use sized_chunks::Grid;
+/// # use typenum::{U2, U3};
+/// let grid: Grid<usize, U2, U3> = Grid::from_fn(|row, col| row * 10 + col);
+/// assert_eq!(12, grid[(1, 2)]);
+/// assert_eq!(&[10, 11, 12], grid.row(1));
+/// assert_eq!(vec![&1, &11], grid.column(1).collect::<Vec<_>>());
+///
+/// let transposed = grid.transpose();
+/// assert_eq!(12, transposed[(2, 1)]);
+/// ```
+///
+/// [Chunk]: ../sized_chunk/struct.Chunk.html
+/// [Unsigned]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.Unsigned.html
+/// [NonZero]: https://docs.rs/typenum/1.10.0/typenum/marker_traits/trait.NonZero.html
+pub struct Grid<A, R, C>
+where
+    R: Unsigned + Mul<C>,
+    C: Unsigned + NonZero,
+    Prod<R, C>: ChunkLength<A>,
+{
+    data: Chunk<A, Prod<R, C>>,
+}
+
+impl<A, R, C> Grid<A, R, C>
+where
+    R: Unsigned + Mul<C>,
+    C: Unsigned + NonZero,
+    Prod<R, C>: ChunkLength<A>,
+{
+    /// The number of rows in the grid.
+    pub const ROWS: usize = R::USIZE;
+
+    /// The number of columns in the grid.
+    pub const COLUMNS: usize = C::USIZE;
+
+    /// Construct a grid by calling `f` with the row and column of each
+    /// position, in row order.
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> A,
+    {
+        let mut data = Chunk::new();
+        for row in 0..R::USIZE {
+            for col in 0..C::USIZE {
+                data.push_back(f(row, col));
+            }
+        }
+        Grid { data }
+    }
+
+    /// Construct a grid with every position set to a clone of `value`.
+    pub fn filled(value: A) -> Self
+    where
+        A: Clone,
+    {
+        Self::from_fn(|_, _| value.clone())
+    }
+
+    /// Get a reference to the value at a given row and column.
+    pub fn get(&self, row: usize, col: usize) -> Option<&A> {
+        if row < R::USIZE && col < C::USIZE {
+            Some(&self.data[row * C::USIZE + col])
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable reference to the value at a given row and column.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut A> {
+        if row < R::USIZE && col < C::USIZE {
+            Some(&mut self.data[row * C::USIZE + col])
+        } else {
+            None
+        }
+    }
+
+    /// Get a row of the grid as a slice.
+    ///
+    /// Panics if `row` is out of bounds.
+    pub fn row(&self, row: usize) -> &[A] {
+        if row >= R::USIZE {
+            panic!("Grid::row: index out of bounds");
+        }
+        &self.data[row * C::USIZE..(row + 1) * C::USIZE]
+    }
+
+    /// Get a row of the grid as a mutable slice.
+    ///
+    /// Panics if `row` is out of bounds.
+    pub fn row_mut(&mut self, row: usize) -> &mut [A] {
+        if row >= R::USIZE {
+            panic!("Grid::row_mut: index out of bounds");
+        }
+        &mut self.data[row * C::USIZE..(row + 1) * C::USIZE]
+    }
+
+    /// Get an iterator over the values in a column of the grid.
+    ///
+    /// Panics if `col` is out of bounds.
+    pub fn column(&self, col: usize) -> Column<'_, A> {
+        if col >= C::USIZE {
+            panic!("Grid::column: index out of bounds");
+        }
+        // With no rows, there's no value at `col` to start from.
+        let start = col.min(self.data.len());
+        self.data[start..].iter().step_by(C::USIZE)
+    }
+
+    /// Get an iterator over mutable references to the values in a column of
+    /// the grid.
+    ///
+    /// Panics if `col` is out of bounds.
+    pub fn column_mut(&mut self, col: usize) -> ColumnMut<'_, A> {
+        if col >= C::USIZE {
+            panic!("Grid::column_mut: index out of bounds");
+        }
+        let start = col.min(self.data.len());
+        self.data[start..].iter_mut().step_by(C::USIZE)
+    }
+
+    /// Get an iterator over the rows of the grid, as slices.
+    pub fn rows(&self) -> ChunksExact<'_, A> {
+        self.data.chunks_exact(C::USIZE)
+    }
+
+    /// Get an iterator over the rows of the grid, as mutable slices.
+    pub fn rows_mut(&mut self) -> ChunksExactMut<'_, A> {
+        self.data.chunks_exact_mut(C::USIZE)
+    }
+
+    /// Get an iterator over the columns of the grid.
+    pub fn columns(&self) -> Columns<'_, A, C> {
+        Columns {
+            data: &self.data,
+            col: 0,
+            width: PhantomData,
+        }
+    }
+
+    /// Turn a grid of `R` rows by `C` columns into a grid of `C` rows by `R`
+    /// columns, where the rows of the new grid are the columns of the old.
+    ///
+    /// Time: O(n)
+    pub fn transpose(self) -> Grid<A, C, R>
+    where
+        R: NonZero,
+        C: Mul<R>,
+        Prod<C, R>: ChunkLength<A>,
+    {
+        let source = ManuallyDrop::new(self.data);
+        let mut data = Chunk::new();
+        for col in 0..C::USIZE {
+            for row in 0..R::USIZE {
+                // Every value is read exactly once, and `source` won't drop
+                // them again.
+                data.push_back(unsafe { ptr::read(&source[row * C::USIZE + col]) });
+            }
+        }
+        Grid { data }
+    }
+
+    /// Discard the grid, returning its values in row order.
+    pub fn into_chunk(self) -> Chunk<A, Prod<R, C>> {
+        self.data
+    }
+}
+
+impl<A, R, C> Deref for Grid<A, R, C>
+where
+    R: Unsigned + Mul<C>,
+    C: Unsigned + NonZero,
+    Prod<R, C>: ChunkLength<A>,
+{
+    type Target = [A];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<A, R, C> DerefMut for Grid<A, R, C>
+where
+    R: Unsigned + Mul<C>,
+    C: Unsigned + NonZero,
+    Prod<R, C>: ChunkLength<A>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+impl<A, R, C> Index<(usize, usize)> for Grid<A, R, C>
+where
+    R: Unsigned + Mul<C>,
+    C: Unsigned + NonZero,
+    Prod<R, C>: ChunkLength<A>,
+{
+    type Output = A;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        match self.get(row, col) {
+            Some(value) => value,
+            None => panic!("Grid::index: index out of bounds"),
+        }
+    }
+}
+
+impl<A, R, C> IndexMut<(usize, usize)> for Grid<A, R, C>
+where
+    R: Unsigned + Mul<C>,
+    C: Unsigned + NonZero,
+    Prod<R, C>: ChunkLength<A>,
+{
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        match self.get_mut(row, col) {
+            Some(value) => value,
+            None => panic!("Grid::index_mut: index out of bounds"),
+        }
+    }
+}
+
+impl<A, R, C> Default for Grid<A, R, C>
+where
+    A: Default,
+    R: Unsigned + Mul<C>,
+    C: Unsigned + NonZero,
+    Prod<R, C>: ChunkLength<A>,
+{
+    fn default() -> Self {
+        Self::from_fn(|_, _| A::default())
+    }
+}
+
+impl<A, R, C> Clone for Grid<A, R, C>
+where
+    A: Clone,
+    R: Unsigned + Mul<C>,
+    C: Unsigned + NonZero,
+    Prod<R, C>: ChunkLength<A>,
+{
+    fn clone(&self) -> Self {
+        Grid {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<A, R, C> PartialEq for Grid<A, R, C>
+where
+    A: PartialEq,
+    R: Unsigned + Mul<C>,
+    C: Unsigned + NonZero,
+    Prod<R, C>: ChunkLength<A>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<A, R, C> Eq for Grid<A, R, C>
+where
+    A: Eq,
+    R: Unsigned + Mul<C>,
+    C: Unsigned + NonZero,
+    Prod<R, C>: ChunkLength<A>,
+{
+}
+
+impl<A, R, C> Hash for Grid<A, R, C>
+where
+    A: Hash,
+    R: Unsigned + Mul<C>,
+    C: Unsigned + NonZero,
+    Prod<R, C>: ChunkLength<A>,
+{
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.data.hash(hasher)
+    }
+}
+
+impl<A, R, C> Debug for Grid<A, R, C>
+where
+    A: Debug,
+    R: Unsigned + Mul<C>,
+    C: Unsigned + NonZero,
+    Prod<R, C>: ChunkLength<A>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("Grid")?;
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
+
+impl<A, R, C> TryFrom<Chunk<A, Prod<R, C>>> for Grid<A, R, C>
+where
+    R: Unsigned + Mul<C>,
+    C: Unsigned + NonZero,
+    Prod<R, C>: ChunkLength<A>,
+{
+    type Error = Chunk<A, Prod<R, C>>;
+
+    /// Turn a full chunk into a grid, reading its values in row order.
+    ///
+    /// Gives the chunk back if it isn't full.
+    fn try_from(data: Chunk<A, Prod<R, C>>) -> Result<Self, Self::Error> {
+        if data.is_full() {
+            Ok(Grid { data })
+        } else {
+            Err(data)
+        }
+    }
+}
+
+/// An iterator over the columns of a [`Grid`][Grid].
+///
+/// Each column is itself an iterator over the column's values.
+///
+/// [Grid]: struct.Grid.html
+pub struct Columns<'a, A, C> {
+    data: &'a [A],
+    col: usize,
+    width: PhantomData<C>,
+}
+
+impl<'a, A, C> Iterator for Columns<'a, A, C>
+where
+    C: Unsigned,
+{
+    type Item = Column<'a, A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.col >= C::USIZE {
+            return None;
+        }
+        let start = self.col.min(self.data.len());
+        self.col += 1;
+        Some(self.data[start..].iter().step_by(C::USIZE))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = C::USIZE - self.col;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, A, C> ExactSizeIterator for Columns<'a, A, C> where C: Unsigned {}
+
+impl<'a, A, C> FusedIterator for Columns<'a, A, C> where C: Unsigned {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tests::DropTest;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use typenum::{U0, U2, U3, U4};
+
+    #[test]
+    fn rows_and_columns() {
+        let mut grid: Grid<usize, U3, U4> = Grid::from_fn(|row, col| row * 4 + col);
+        assert_eq!(12, grid.len());
+        assert_eq!(&[4, 5, 6, 7], grid.row(1));
+        assert_eq!(vec![2, 6, 10], grid.column(2).cloned().collect::<Vec<_>>());
+        assert_eq!(3, grid.rows().len());
+        assert_eq!(4, grid.columns().len());
+        for value in grid.column_mut(0) {
+            *value = 0;
+        }
+        grid[(2, 3)] = 99;
+        assert_eq!(&[0, 9, 10, 99], grid.row(2));
+        assert_eq!(None, grid.get(3, 0));
+        assert_eq!(None, grid.get(0, 4));
+    }
+
+    #[test]
+    fn transpose() {
+        let grid: Grid<usize, U2, U3> = Grid::from_fn(|row, col| row * 3 + col);
+        let transposed: Grid<usize, U3, U2> = grid.clone().transpose();
+        for row in 0..2 {
+            for col in 0..3 {
+                assert_eq!(grid[(row, col)], transposed[(col, row)]);
+            }
+        }
+        assert_eq!(grid, transposed.transpose());
+    }
+
+    #[test]
+    fn transpose_drops_once() {
+        let counter = AtomicUsize::new(0);
+        {
+            let grid: Grid<DropTest<'_>, U2, U3> = Grid::from_fn(|_, _| DropTest::new(&counter));
+            let transposed = grid.transpose();
+            assert_eq!(6, counter.load(Ordering::Relaxed));
+            drop(transposed);
+        }
+        assert_eq!(0, counter.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn from_chunk() {
+        let chunk: Chunk<i32, U4> = (0..3).collect();
+        let chunk = Grid::<i32, U2, U2>::try_from(chunk).unwrap_err();
+        let grid = Grid::<i32, U2, U2>::try_from(
+            chunk.into_iter().chain(Some(3)).collect::<Chunk<_, U4>>(),
+        )
+        .unwrap();
+        assert_eq!(&[2, 3], grid.row(1));
+    }
+
+    #[test]
+    fn zero_rows() {
+        let mut grid: Grid<i32, U0, U3> = Grid::default();
+        assert!(grid.is_empty());
+        assert_eq!(0, grid.rows().len());
+        assert_eq!(0, grid.column(2).count());
+        assert_eq!(0, grid.column_mut(1).count());
+        assert_eq!(
+            vec![0, 0, 0],
+            grid.columns().map(Iterator::count).collect::<Vec<_>>()
+        );
+        assert_eq!(None, grid.get(0, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Grid::index: index out of bounds")]
+    fn index_out_of_bounds() {
+        let grid: Grid<i32, U2, U2> = Grid::default();
+        let _ = grid[(0, 2)];
+    }
+}
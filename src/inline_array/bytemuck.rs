@@ -0,0 +1,84 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::ops::Deref;
+use std::ptr;
+
+use ::bytemuck::{cast_slice, Pod, PodCastError, Zeroable};
+
+use crate::sized_chunk::bytemuck::pod_len;
+use crate::InlineArray;
+
+impl<A, T> InlineArray<A, T>
+where
+    A: Pod,
+{
+    /// Get the contents of the array as a slice of bytes.
+    ///
+    /// This is only available with the `bytemuck` feature enabled.
+    pub fn as_bytes(&self) -> &[u8] {
+        cast_slice(self.deref())
+    }
+
+    /// Construct an array by copying its contents out of a slice of bytes.
+    ///
+    /// The bytes don't need to be aligned for `A`. Fails with
+    /// [`PodCastError::OutputSliceWouldHaveSlop`][PodCastError] if the length
+    /// of `bytes` isn't a multiple of the size of `A`, or with
+    /// [`PodCastError::SizeMismatch`][PodCastError] if the values won't fit in
+    /// the array.
+    ///
+    /// This is only available with the `bytemuck` feature enabled.
+    ///
+    /// [PodCastError]: https://docs.rs/bytemuck/latest/bytemuck/enum.PodCastError.html
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, PodCastError> {
+        let len = pod_len::<A>(bytes, Self::CAPACITY)?;
+        let mut array = Self::new();
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), array.data_mut() as *mut u8, bytes.len());
+            *array.len_mut() = len;
+        }
+        Ok(array)
+    }
+}
+
+impl<A, T> InlineArray<A, T>
+where
+    A: Zeroable,
+{
+    /// Construct a full array where every value is zeroed.
+    ///
+    /// This is only available with the `bytemuck` feature enabled.
+    pub fn zeroed() -> Self {
+        let mut array = Self::new();
+        unsafe {
+            ptr::write_bytes(array.data_mut(), 0, Self::CAPACITY);
+            *array.len_mut() = Self::CAPACITY;
+        }
+        array
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bytes() {
+        let mut array: InlineArray<u32, [usize; 3]> = InlineArray::new();
+        array.push(0x0102_0304);
+        let bytes = array.as_bytes().to_vec();
+        assert_eq!(4, bytes.len());
+        assert_eq!(
+            array,
+            InlineArray::<u32, [usize; 3]>::try_from_bytes(&bytes).unwrap()
+        );
+        assert_eq!(
+            Err(PodCastError::SizeMismatch),
+            InlineArray::<u32, [usize; 3]>::try_from_bytes(&[0; 20])
+        );
+        let zeroed: InlineArray<u32, [usize; 3]> = InlineArray::zeroed();
+        assert_eq!(&[0, 0, 0, 0], zeroed.deref());
+    }
+}
@@ -8,18 +8,29 @@
 
 use std::borrow::{Borrow, BorrowMut};
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::fmt::{Debug, Error, Formatter};
 use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
 use std::marker::PhantomData;
-use std::mem::{self, MaybeUninit};
+use std::mem::{self, ManuallyDrop, MaybeUninit};
 use std::ops::{Deref, DerefMut};
 use std::ptr;
 use std::slice::{from_raw_parts, from_raw_parts_mut, Iter as SliceIter, IterMut as SliceIterMut};
 
+use crate::ring_buffer::{RingBuffer, Slice as RingSlice, SliceMut as RingSliceMut};
+use crate::sized_chunk::Chunk;
+use crate::types::{ChunkLength, FixedSeq};
+
 mod iter;
 pub use self::iter::{Drain, Iter};
 
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+
 /// A fixed capacity array sized to match some other type `T`.
 ///
 /// This works like a vector, but allocated on the stack (and thus marginally
@@ -105,7 +116,7 @@ impl<A, T> InlineArray<A, T> {
 
     #[inline]
     #[must_use]
-    unsafe fn data_mut(&mut self) -> *mut A {
+    pub(crate) unsafe fn data_mut(&mut self) -> *mut A {
         self.len_mut().add(1) as *mut _ as *mut A
     }
 
@@ -156,7 +167,10 @@ impl<A, T> InlineArray<A, T> {
     #[inline]
     #[must_use]
     pub fn new() -> Self {
-        debug_assert!(Self::HOST_SIZE > Self::HEADER_SIZE);
+        check!(
+            Self::HOST_SIZE > Self::HEADER_SIZE,
+            "InlineArray::new: host type too small to hold the length header"
+        );
         let mut self_ = Self {
             data: MaybeUninit::uninit(),
             phantom: PhantomData,
@@ -165,6 +179,63 @@ impl<A, T> InlineArray<A, T> {
         self_
     }
 
+    /// Construct a new empty array in place.
+    ///
+    /// This only initialises the array's bookkeeping, leaving its storage
+    /// untouched, so it's cheap however large the array is, and the array
+    /// never needs to exist on the stack.
+    pub fn init_in_place(target: &mut MaybeUninit<Self>) -> &mut Self {
+        unsafe {
+            let ptr = target.as_mut_ptr();
+            check!(
+                Self::HOST_SIZE > Self::HEADER_SIZE,
+                "InlineArray::init_in_place: host type too small to hold the length header"
+            );
+            (*ptr).len_mut().write(0);
+            &mut *ptr
+        }
+    }
+
+    /// Construct a new empty array directly on the heap.
+    ///
+    /// Unlike `Box::new(InlineArray::new())`, this never builds the array on the
+    /// stack first, so it's safe to use for arrays too large to fit there.
+    pub fn new_boxed() -> Box<Self> {
+        let mut boxed = crate::boxed::new_uninit::<Self>();
+        Self::init_in_place(&mut boxed);
+        unsafe { crate::boxed::assume_init(boxed) }
+    }
+
+    /// Clone the array directly onto the heap.
+    ///
+    /// See [`new_boxed`](#method.new_boxed).
+    pub fn clone_boxed(&self) -> Box<Self>
+    where
+        A: Clone,
+    {
+        let mut out = Self::new_boxed();
+        for value in self.iter() {
+            out.push(value.clone());
+        }
+        out
+    }
+
+    /// Construct an array directly on the heap from an iterator.
+    ///
+    /// See [`new_boxed`](#method.new_boxed).
+    ///
+    /// Panics if the iterator yields more values than the array can hold.
+    pub fn collect_boxed<I>(iter: I) -> Box<Self>
+    where
+        I: IntoIterator<Item = A>,
+    {
+        let mut out = Self::new_boxed();
+        for value in iter {
+            out.push(value);
+        }
+        out
+    }
+
     /// Push an item to the back of the array.
     ///
     /// Panics if the capacity of the array is exceeded.
@@ -269,9 +340,10 @@ impl<A, T> InlineArray<A, T> {
     ///
     /// Time: O(n)
     pub fn clear(&mut self) {
+        let len = self.len();
         unsafe {
-        self.drop_contents();
             *self.len_mut() = 0;
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.data_mut(), len));
         }
     }
 
@@ -284,6 +356,8 @@ impl<A, T> InlineArray<A, T> {
 impl<A, T> Drop for InlineArray<A, T> {
     fn drop(&mut self) {
         unsafe { self.drop_contents() }
+        #[cfg(feature = "zeroize")]
+        crate::types::zeroize_storage(&mut self.data);
     }
 }
 
@@ -302,13 +376,8 @@ where
 {
     fn clone(&self) -> Self {
         let mut copy = Self::new();
-        for i in 0..self.len() {
-            unsafe {
-                copy.write_at(i, self.get_unchecked(i).clone());
-            }
-        }
-        unsafe {
-            *copy.len_mut() = self.len();
+        for value in self.iter() {
+            copy.push(value.clone());
         }
         copy
     }
@@ -350,6 +419,7 @@ impl<A, T> AsMut<[A]> for InlineArray<A, T> {
         self.deref_mut()
     }
 }
+
 impl<A, T, Slice> PartialEq<Slice> for InlineArray<A, T>
 where
     Slice: Borrow<[A]>,
@@ -360,13 +430,74 @@ where
     }
 }
 
+impl<A, T, N> PartialEq<RingBuffer<A, N>> for InlineArray<A, T>
+where
+    A: PartialEq,
+    N: ChunkLength<A>,
+{
+    fn eq(&self, other: &RingBuffer<A, N>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<A, T, N> PartialEq<RingSlice<'_, A, N>> for InlineArray<A, T>
+where
+    A: PartialEq,
+    N: ChunkLength<A>,
+{
+    fn eq(&self, other: &RingSlice<'_, A, N>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<A, T, N> PartialEq<RingSliceMut<'_, A, N>> for InlineArray<A, T>
+where
+    A: PartialEq,
+    N: ChunkLength<A>,
+{
+    fn eq(&self, other: &RingSliceMut<'_, A, N>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
 impl<A, T> Eq for InlineArray<A, T> where A: Eq {}
 
-impl<A, T> PartialOrd for InlineArray<A, T>
+impl<A, T, Slice> PartialOrd<Slice> for InlineArray<A, T>
 where
+    Slice: Borrow<[A]>,
     A: PartialOrd,
 {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    fn partial_cmp(&self, other: &Slice) -> Option<Ordering> {
+        self.deref().partial_cmp(other.borrow())
+    }
+}
+
+impl<A, T, N> PartialOrd<RingBuffer<A, N>> for InlineArray<A, T>
+where
+    A: PartialOrd,
+    N: ChunkLength<A>,
+{
+    fn partial_cmp(&self, other: &RingBuffer<A, N>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<A, T, N> PartialOrd<RingSlice<'_, A, N>> for InlineArray<A, T>
+where
+    A: PartialOrd,
+    N: ChunkLength<A>,
+{
+    fn partial_cmp(&self, other: &RingSlice<'_, A, N>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<A, T, N> PartialOrd<RingSliceMut<'_, A, N>> for InlineArray<A, T>
+where
+    A: PartialOrd,
+    N: ChunkLength<A>,
+{
+    fn partial_cmp(&self, other: &RingSliceMut<'_, A, N>) -> Option<Ordering> {
         self.iter().partial_cmp(other.iter())
     }
 }
@@ -394,13 +525,15 @@ impl<A, T> Hash for InlineArray<A, T>
 where
     A: Hash,
 {
+    /// Hash the array's contents.
+    ///
+    /// This produces exactly the same hash as the equivalent `[A]` slice, as
+    /// required by the `Borrow<[A]>` implementation.
     fn hash<H>(&self, hasher: &mut H)
     where
         H: Hasher,
     {
-        for item in self {
-            item.hash(hasher)
-        }
+        self.deref().hash(hasher)
     }
 }
 
@@ -425,6 +558,117 @@ impl<A, T> FromIterator<A> for InlineArray<A, T> {
     }
 }
 
+impl<A, T, N> TryFrom<Chunk<A, N>> for InlineArray<A, T>
+where
+    N: ChunkLength<A>,
+{
+    type Error = Chunk<A, N>;
+
+    /// Move the contents of a `Chunk` into a new array.
+    ///
+    /// Fails and gives you the chunk back if it's too large to fit in the
+    /// array.
+    fn try_from(chunk: Chunk<A, N>) -> Result<Self, Self::Error> {
+        if chunk.len() > Self::CAPACITY {
+            return Err(chunk);
+        }
+        let mut out = Self::new();
+        unsafe {
+            ptr::copy_nonoverlapping(chunk.as_ptr(), out.data_mut(), chunk.len());
+            *out.len_mut() = chunk.len();
+        }
+        mem::forget(chunk);
+        Ok(out)
+    }
+}
+
+impl<A, T, N> TryFrom<RingBuffer<A, N>> for InlineArray<A, T>
+where
+    N: ChunkLength<A>,
+{
+    type Error = RingBuffer<A, N>;
+
+    /// Move the contents of a `RingBuffer` into a new array.
+    ///
+    /// Fails and gives you the buffer back if it's too large to fit in the
+    /// array.
+    fn try_from(buffer: RingBuffer<A, N>) -> Result<Self, Self::Error> {
+        if buffer.len() > Self::CAPACITY {
+            return Err(buffer);
+        }
+        let mut out = Self::new();
+        let (left, right) = buffer.slices(0..buffer.len());
+        unsafe {
+            ptr::copy_nonoverlapping(left.as_ptr(), out.data_mut(), left.len());
+            ptr::copy_nonoverlapping(right.as_ptr(), out.ptr_at_mut(left.len()), right.len());
+            *out.len_mut() = buffer.len();
+        }
+        mem::forget(buffer);
+        Ok(out)
+    }
+}
+
+impl<A, T, const K: usize> From<[A; K]> for InlineArray<A, T> {
+    /// Move the contents of an array into a new `InlineArray`.
+    ///
+    /// It's a compile time error if `K` is larger than the `InlineArray`'s
+    /// capacity.
+    fn from(array: [A; K]) -> Self {
+        const {
+            assert!(
+                K <= Self::CAPACITY,
+                "InlineArray::from: chunk size overflow"
+            )
+        };
+        let array = ManuallyDrop::new(array);
+        let mut out = Self::new();
+        unsafe {
+            ptr::copy_nonoverlapping(array.as_ptr(), out.data_mut(), K);
+            *out.len_mut() = K;
+        }
+        out
+    }
+}
+
+impl<A, T> TryFrom<Vec<A>> for InlineArray<A, T> {
+    type Error = Vec<A>;
+
+    /// Move the contents of a `Vec` into a new array.
+    ///
+    /// Fails and gives you the `Vec` back if it's too large to fit in the
+    /// array.
+    fn try_from(mut vec: Vec<A>) -> Result<Self, Self::Error> {
+        if vec.len() > Self::CAPACITY {
+            return Err(vec);
+        }
+        let mut out = Self::new();
+        unsafe {
+            ptr::copy_nonoverlapping(vec.as_ptr(), out.data_mut(), vec.len());
+            *out.len_mut() = vec.len();
+            vec.set_len(0);
+        }
+        Ok(out)
+    }
+}
+
+impl<'a, A, T> TryFrom<&'a [A]> for InlineArray<A, T>
+where
+    A: Clone,
+{
+    type Error = &'a [A];
+
+    /// Construct a new array by cloning the contents of a slice.
+    ///
+    /// Fails and gives you the slice back if it's too large to fit in the
+    /// array.
+    fn try_from(slice: &'a [A]) -> Result<Self, Self::Error> {
+        if slice.len() > Self::CAPACITY {
+            return Err(slice);
+        }
+        Ok(slice.iter().cloned().collect())
+    }
+}
+
 impl<'a, A, T> IntoIterator for &'a InlineArray<A, T> {
     type Item = &'a A;
     type IntoIter = SliceIter<'a, A>;
@@ -476,12 +720,92 @@ where
     }
 }
 
+impl<A, T> FixedSeq for InlineArray<A, T> {
+    type Item = A;
+    type Iter<'a>
+        = SliceIter<'a, A>
+    where
+        Self: 'a;
+    type Drain<'a>
+        = Drain<'a, A, T>
+    where
+        Self: 'a;
+
+    const CAPACITY: usize = InlineArray::<A, T>::CAPACITY;
+
+    fn len(&self) -> usize {
+        InlineArray::len(self)
+    }
+
+    fn push_back(&mut self, value: A) {
+        InlineArray::push(self, value)
+    }
+
+    fn pop_back(&mut self) -> Option<A> {
+        InlineArray::pop(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&A> {
+        self.as_ref().get(index)
+    }
+
+    fn insert(&mut self, index: usize, value: A) {
+        InlineArray::insert(self, index, value)
+    }
+
+    fn remove(&mut self, index: usize) -> A {
+        match InlineArray::remove(self, index) {
+            Some(value) => value,
+            None => panic!("InlineArray::remove: index out of bounds"),
+        }
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.as_ref().iter()
+    }
+
+    fn drain(&mut self) -> Self::Drain<'_> {
+        InlineArray::drain(self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::tests::DropTest;
+    use crate::tests::{assert_panics, DropTest, PanicOnClone};
     use std::sync::atomic::{AtomicUsize, Ordering};
 
+    #[test]
+    fn clone_panic_safety() {
+        let counter = AtomicUsize::new(0);
+        {
+            let mut array: InlineArray<PanicOnClone<'_>, [usize; 8]> = InlineArray::new();
+            array.push(PanicOnClone::new(&counter));
+            array.push(PanicOnClone::panicking(&counter));
+            assert_panics(|| drop(array.clone()));
+            assert_eq!(2, counter.load(Ordering::Relaxed));
+        }
+        assert_eq!(0, counter.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn conversions() {
+        type Array = InlineArray<u64, [usize; 5]>;
+        let mut buffer: RingBuffer<u64> = RingBuffer::new();
+        buffer.push_back(2);
+        buffer.push_back(3);
+        buffer.push_front(1);
+        buffer.push_front(0);
+        assert_eq!(Array::try_from(buffer).unwrap(), [0, 1, 2, 3]);
+        let chunk: Chunk<u64> = (0..5).collect();
+        let chunk = Array::try_from(chunk).unwrap_err();
+        assert_eq!(5, chunk.len());
+        assert_eq!(Array::from([1, 2]), [1, 2]);
+        assert_eq!(Err(vec![0; 5]), Array::try_from(vec![0; 5]));
+        let slice: &[u64] = &[1, 2, 3];
+        assert_eq!(Array::try_from(slice).unwrap(), slice);
+    }
+
     #[test]
     fn dropping() {
         let counter = AtomicUsize::new(0);
@@ -498,4 +822,35 @@ mod test {
         }
         assert_eq!(0, counter.load(Ordering::Relaxed));
     }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "checked"))]
+    #[should_panic(expected = "InlineArray::new: host type too small to hold the length header")]
+    fn new_without_room_for_header() {
+        let _: InlineArray<u8, usize> = InlineArray::new();
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "checked"))]
+    #[should_panic(
+        expected = "InlineArray::init_in_place: host type too small to hold the length header"
+    )]
+    fn init_in_place_without_room_for_header() {
+        let mut slot = MaybeUninit::<InlineArray<u8, usize>>::uninit();
+        let _ = InlineArray::init_in_place(&mut slot);
+    }
+
+    #[test]
+    fn boxed_arrays() {
+        let mut slot = MaybeUninit::uninit();
+        assert!(InlineArray::<u32, [usize; 4]>::init_in_place(&mut slot).is_empty());
+        // A 128k array on a 64k stack would overflow if it ever touched it.
+        crate::tests::on_small_stack(|| {
+            type Array = InlineArray<u64, [u64; 16384]>;
+            let array = Array::collect_boxed(0..Array::CAPACITY as u64);
+            assert!(array.is_full());
+            assert_eq!(*array, *array.clone_boxed());
+            assert!(Array::new_boxed().is_empty());
+        });
+    }
 }
@@ -0,0 +1,42 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use ::zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::InlineArray;
+
+impl<A, T> Zeroize for InlineArray<A, T> {
+    /// Drop the contents of the array and overwrite its entire backing store,
+    /// including the unused capacity, with zeroes.
+    ///
+    /// This also zeroes the array's length, leaving it empty.
+    fn zeroize(&mut self) {
+        self.clear();
+        crate::types::zeroize_storage(&mut self.data);
+    }
+}
+
+impl<A, T> ZeroizeOnDrop for InlineArray<A, T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::mem;
+
+    #[test]
+    fn zeroize_wipes_full_capacity() {
+        let mut array: InlineArray<u8, [usize; 4]> = InlineArray::new();
+        array.extend(1..=20);
+        array.pop();
+        array.zeroize();
+        assert!(array.is_empty());
+        let storage = unsafe {
+            std::slice::from_raw_parts(
+                &array.data as *const _ as *const u8,
+                mem::size_of::<[usize; 4]>(),
+            )
+        };
+        assert!(storage.iter().all(|byte| *byte == 0));
+    }
+}
@@ -0,0 +1,372 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! Iterator adaptors which batch values into chunks.
+//!
+//! Bring [`ChunkIteratorExt`][ChunkIteratorExt] into scope to add these to
+//! every iterator.
+//!
+//! [ChunkIteratorExt]: trait.ChunkIteratorExt.html
+
+use std::fmt::{Debug, Error, Formatter};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+use bitmaps::Bits;
+use typenum::NonZero;
+
+use crate::ring_buffer::{RingBuffer, Slice};
+use crate::sized_chunk::Chunk;
+use crate::sparse_chunk::SparseChunk;
+use crate::types::ChunkLength;
+
+/// Extension methods for batching the values of an iterator into chunks.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sized_chunks::iter::ChunkIteratorExt;
+/// # use typenum::U4;
+/// let sums: Vec<i32> = (0..10)
+///     .chunked::<U4>()
+///     .map(|chunk| chunk.iter().sum())
+///     .collect();
+/// assert_eq!(vec![6, 22, 17], sums);
+/// ```
+pub trait ChunkIteratorExt: Iterator + Sized {
+    /// Batch the iterator's values into chunks of `N` values.
+    ///
+    /// Every chunk is full except perhaps the last one, and no chunk is empty.
+    fn chunked<N>(self) -> Chunked<Self, N>
+    where
+        N: ChunkLength<Self::Item> + NonZero,
+    {
+        Chunked {
+            iter: self,
+            size: PhantomData,
+        }
+    }
+
+    /// Make an iterator over overlapping windows of `N` consecutive values,
+    /// like `slice::windows`, held in a [`RingBuffer`][RingBuffer] which
+    /// moves along the iterator one value at a time.
+    ///
+    /// If the iterator has fewer than `N` values, there are no windows.
+    ///
+    /// [RingBuffer]: ../struct.RingBuffer.html
+    fn ring_windows<N>(self) -> RingWindows<Self, N>
+    where
+        N: ChunkLength<Self::Item> + NonZero,
+    {
+        RingWindows {
+            iter: self,
+            buffer: RingBuffer::new(),
+            done: false,
+        }
+    }
+
+    /// Group consecutive values which `index_fn` maps to the same block of
+    /// `N` indices into sparse chunks.
+    ///
+    /// A value with index `i` is placed at index `i % N` of its chunk, and a
+    /// new chunk is started whenever `i / N` changes. If two values in a group
+    /// have the same index, the later one replaces the earlier one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sized_chunks::iter::ChunkIteratorExt;
+    /// # use typenum::U8;
+    /// let groups: Vec<Vec<usize>> = vec![1, 3, 12, 14, 15, 2]
+    ///     .into_iter()
+    ///     .sparse_group_by::<U8, _>(|value| *value)
+    ///     .map(|chunk| chunk.indices().collect())
+    ///     .collect();
+    /// assert_eq!(vec![vec![1, 3], vec![4, 6, 7], vec![2]], groups);
+    /// ```
+    fn sparse_group_by<N, F>(self, index_fn: F) -> SparseGroupBy<Self, N, F>
+    where
+        N: Bits + ChunkLength<Self::Item> + NonZero,
+        F: FnMut(&Self::Item) -> usize,
+    {
+        SparseGroupBy {
+            iter: self,
+            index_fn,
+            pending: None,
+            size: PhantomData,
+        }
+    }
+}
+
+impl<I: Iterator> ChunkIteratorExt for I {}
+
+/// An iterator which batches values into [`Chunk`][Chunk]s.
+///
+/// See [`ChunkIteratorExt::chunked`][chunked].
+///
+/// [Chunk]: ../struct.Chunk.html
+/// [chunked]: trait.ChunkIteratorExt.html#method.chunked
+pub struct Chunked<I, N> {
+    iter: I,
+    size: PhantomData<N>,
+}
+
+impl<I, N> Iterator for Chunked<I, N>
+where
+    I: Iterator,
+    N: ChunkLength<I::Item>,
+{
+    type Item = Chunk<I::Item, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        let mut chunk = Chunk::unit(first);
+        chunk.extend(self.iter.by_ref().take(N::USIZE - 1));
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        let batches = |len: usize| match len % N::USIZE {
+            0 => len / N::USIZE,
+            _ => len / N::USIZE + 1,
+        };
+        (batches(low), high.map(batches))
+    }
+}
+
+impl<I, N> FusedIterator for Chunked<I, N>
+where
+    I: FusedIterator,
+    N: ChunkLength<I::Item>,
+{
+}
+
+impl<I: Debug, N> Debug for Chunked<I, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_struct("Chunked").field("iter", &self.iter).finish()
+    }
+}
+
+/// Overlapping windows of consecutive values, held in a
+/// [`RingBuffer`][RingBuffer].
+///
+/// See [`ChunkIteratorExt::ring_windows`][ring_windows].
+///
+/// Use [`next_window`][next_window] to look at each window in place, or, if
+/// the values are `Clone`, iterate over copies of the windows.
+///
+/// [RingBuffer]: ../struct.RingBuffer.html
+/// [ring_windows]: trait.ChunkIteratorExt.html#method.ring_windows
+/// [next_window]: #method.next_window
+pub struct RingWindows<I, N>
+where
+    I: Iterator,
+    N: ChunkLength<I::Item>,
+{
+    iter: I,
+    buffer: RingBuffer<I::Item, N>,
+    done: bool,
+}
+
+impl<I, N> RingWindows<I, N>
+where
+    I: Iterator,
+    N: ChunkLength<I::Item>,
+{
+    /// Move the window along by one value and return a view of it.
+    ///
+    /// Returns `None` once the iterator runs out of values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sized_chunks::iter::ChunkIteratorExt;
+    /// # use typenum::U3;
+    /// let mut windows = (0..5).ring_windows::<U3>();
+    /// let mut sums = Vec::new();
+    /// while let Some(window) = windows.next_window() {
+    ///     sums.push(window.iter().sum::<i32>());
+    /// }
+    /// assert_eq!(vec![3, 6, 9], sums);
+    /// ```
+    pub fn next_window(&mut self) -> Option<Slice<'_, I::Item, N>> {
+        if self.done {
+            return None;
+        }
+        if self.buffer.is_full() {
+            self.buffer.pop_front();
+        }
+        while !self.buffer.is_full() {
+            match self.iter.next() {
+                Some(value) => self.buffer.push_back(value),
+                None => {
+                    self.done = true;
+                    return None;
+                }
+            }
+        }
+        Some(self.buffer.slice(..))
+    }
+}
+
+impl<I, N> Iterator for RingWindows<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+    N: ChunkLength<I::Item>,
+{
+    type Item = RingBuffer<I::Item, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_window()?;
+        Some(self.buffer.clone())
+    }
+}
+
+impl<I, N> FusedIterator for RingWindows<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+    N: ChunkLength<I::Item>,
+{
+}
+
+impl<I, N> Debug for RingWindows<I, N>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+    N: ChunkLength<I::Item>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_struct("RingWindows")
+            .field("iter", &self.iter)
+            .field("buffer", &self.buffer)
+            .finish()
+    }
+}
+
+/// An iterator which groups values into [`SparseChunk`][SparseChunk]s by
+/// their index.
+///
+/// See [`ChunkIteratorExt::sparse_group_by`][sparse_group_by].
+///
+/// [SparseChunk]: ../struct.SparseChunk.html
+/// [sparse_group_by]: trait.ChunkIteratorExt.html#method.sparse_group_by
+pub struct SparseGroupBy<I, N, F>
+where
+    I: Iterator,
+{
+    iter: I,
+    index_fn: F,
+    pending: Option<(usize, I::Item)>,
+    size: PhantomData<N>,
+}
+
+impl<I, N, F> SparseGroupBy<I, N, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> usize,
+{
+    fn next_indexed(&mut self) -> Option<(usize, I::Item)> {
+        match self.pending.take() {
+            Some(pending) => Some(pending),
+            None => {
+                let value = self.iter.next()?;
+                Some(((self.index_fn)(&value), value))
+            }
+        }
+    }
+}
+
+impl<I, N, F> Iterator for SparseGroupBy<I, N, F>
+where
+    I: Iterator,
+    N: Bits + ChunkLength<I::Item>,
+    F: FnMut(&I::Item) -> usize,
+{
+    type Item = SparseChunk<I::Item, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, value) = self.next_indexed()?;
+        let group = index / N::USIZE;
+        let mut chunk = SparseChunk::unit(index % N::USIZE, value);
+        while let Some((index, value)) = self.next_indexed() {
+            if index / N::USIZE != group {
+                self.pending = Some((index, value));
+                break;
+            }
+            chunk.insert(index % N::USIZE, value);
+        }
+        Some(chunk)
+    }
+}
+
+impl<I, N, F> FusedIterator for SparseGroupBy<I, N, F>
+where
+    I: FusedIterator,
+    N: Bits + ChunkLength<I::Item>,
+    F: FnMut(&I::Item) -> usize,
+{
+}
+
+impl<I, N, F> Debug for SparseGroupBy<I, N, F>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_struct("SparseGroupBy")
+            .field("iter", &self.iter)
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use typenum::{U3, U4};
+
+    #[test]
+    fn chunked() {
+        let chunks: Vec<Vec<i32>> = (0..7)
+            .chunked::<U3>()
+            .map(|chunk| chunk.into_iter().collect())
+            .collect();
+        assert_eq!(vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]], chunks);
+        assert_eq!((3, Some(3)), (0..7).chunked::<U3>().size_hint());
+        assert_eq!(0, (0..0).chunked::<U3>().count());
+    }
+
+    #[test]
+    fn ring_windows() {
+        let windows: Vec<Vec<i32>> = (0..6)
+            .ring_windows::<U4>()
+            .map(|window| window.into_iter().collect())
+            .collect();
+        assert_eq!(
+            vec![vec![0, 1, 2, 3], vec![1, 2, 3, 4], vec![2, 3, 4, 5]],
+            windows
+        );
+        let mut windows = (0..3).ring_windows::<U4>();
+        assert!(windows.next_window().is_none());
+        assert!(windows.next_window().is_none());
+    }
+
+    #[test]
+    fn sparse_group_by() {
+        let mut groups = vec![(0, 'a'), (2, 'b'), (2, 'c'), (5, 'd'), (1, 'e')]
+            .into_iter()
+            .sparse_group_by::<U4, _>(|(index, _)| *index);
+        let group = groups.next().unwrap();
+        assert_eq!(vec![0, 2], group.indices().collect::<Vec<_>>());
+        assert_eq!(Some(&(2, 'c')), group.get(2));
+        let group = groups.next().unwrap();
+        assert_eq!(Some(&(5, 'd')), group.get(1));
+        let group = groups.next().unwrap();
+        assert_eq!(Some(&(1, 'e')), group.get(1));
+        assert!(groups.next().is_none());
+    }
+}
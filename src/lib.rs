@@ -28,6 +28,12 @@
 //! | [`Chunk`][Chunk] | Contiguous array | O(1)/O(n) | O(1) | Yes |
 //! | [`RingBuffer`][RingBuffer] | Non-contiguous array | O(1) | O(1) | No |
 //! | [`SparseChunk`][SparseChunk] | Sparse array | N/A | N/A | No |
+//! | [`ChunkString`][ChunkString] | UTF-8 string | O(1)/O(n) | O(1) | No, to `&str` |
+//! | [`ChunkMap`][ChunkMap] | Sorted map | N/A | N/A | No |
+//! | [`ChunkSet`][ChunkSet] | Sorted set | N/A | N/A | No |
+//! | [`ChunkHeap`][ChunkHeap] | Priority queue | O(log n) | O(log n) | No |
+//! | [`SparseSet`][SparseSet] | Bitset | N/A | N/A | No |
+//! | [`Grid`][Grid] | Two dimensional array | N/A | N/A | Yes |
 //!
 //! The [`Chunk`][Chunk] and [`RingBuffer`][RingBuffer] are very similar in
 //! practice, in that they both work like a plain array, except that you can
@@ -61,12 +67,36 @@
 //! where the `usize` must be less than `N`, but without the performance
 //! overhead. Its API is also more consistent with a map than an array - there's
 //! no push, pop, append, etc, just insert, remove and lookup.
+//! [`SparseSet`][SparseSet] is the same bitmap without the values, for when
+//! you only need to know which indices are present.
+//!
+//! [`ChunkString`][ChunkString] wraps a [`Chunk`][Chunk] of bytes to give you
+//! a fixed capacity `String` which can be extended at either end.
+//!
+//! [`ChunkMap`][ChunkMap] and [`ChunkSet`][ChunkSet] keep their keys sorted in
+//! a [`Chunk`][Chunk], giving you fixed capacity versions of `BTreeMap` and
+//! `BTreeSet` which look up keys using a binary search, and
+//! [`ChunkHeap`][ChunkHeap] is a fixed capacity `BinaryHeap`.
+//!
+//! [`Grid`][Grid] is a fixed size two dimensional array, sized by a number of
+//! rows and a number of columns, which you can index by row and column.
+//!
+//! The [`iter`][iter] module's [`ChunkIteratorExt`][ChunkIteratorExt] trait
+//! adds methods to every iterator for batching its values into chunks.
 //!
 //! [immutable.rs]: https://immutable.rs/
 //! [typenum]: https://docs.rs/typenum/
 //! [Chunk]: struct.Chunk.html
 //! [RingBuffer]: struct.RingBuffer.html
 //! [SparseChunk]: struct.SparseChunk.html
+//! [SparseSet]: struct.SparseSet.html
+//! [ChunkString]: struct.ChunkString.html
+//! [ChunkMap]: struct.ChunkMap.html
+//! [ChunkSet]: struct.ChunkSet.html
+//! [ChunkHeap]: struct.ChunkHeap.html
+//! [Grid]: struct.Grid.html
+//! [iter]: iter/index.html
+//! [ChunkIteratorExt]: iter/trait.ChunkIteratorExt.html
 //! [ArrayVec]: https://docs.rs/arrayvec/
 
 #![forbid(rust_2018_idioms)]
@@ -74,19 +104,56 @@
 #![warn(unreachable_pub, missing_docs)]
 #![cfg_attr(test, deny(warnings))]
 
+/// Check an internal precondition.
+///
+/// This is a `debug_assert!`, unless the `checked` feature is enabled, in
+/// which case it's an `assert!` and misuse panics in release builds too.
+macro_rules! check {
+    ($($arg:tt)*) => {
+        if cfg!(feature = "checked") {
+            assert!($($arg)*)
+        } else {
+            debug_assert!($($arg)*)
+        }
+    };
+}
+
+pub mod chunk_heap;
+pub mod chunk_map;
+pub mod chunk_set;
+pub mod chunk_string;
+pub mod grid;
 pub mod inline_array;
+pub mod iter;
 pub mod ring_buffer;
 pub mod sized_chunk;
 pub mod sparse_chunk;
+pub mod sparse_set;
 pub mod types;
 
+mod boxed;
+
 #[cfg(test)]
 mod tests;
 
 #[cfg(feature = "arbitrary")]
 mod arbitrary;
 
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+#[cfg(feature = "rayon")]
+pub mod rayon;
+
+pub use crate::chunk_heap::ChunkHeap;
+pub use crate::chunk_map::ChunkMap;
+pub use crate::chunk_set::ChunkSet;
+pub use crate::chunk_string::ChunkString;
+pub use crate::grid::Grid;
 pub use crate::inline_array::InlineArray;
 pub use crate::ring_buffer::RingBuffer;
 pub use crate::sized_chunk::Chunk;
+#[cfg(feature = "bytemuck")]
+pub use crate::sized_chunk::PodChunk;
 pub use crate::sparse_chunk::SparseChunk;
+pub use crate::sparse_set::SparseSet;
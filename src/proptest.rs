@@ -0,0 +1,246 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! [`proptest`][proptest] strategies for the chunk types.
+//!
+//! This module is only available with the `proptest` feature enabled. Every
+//! chunk type also implements [`proptest::arbitrary::Arbitrary`][Arbitrary],
+//! so you can use `any::<Chunk<A, N>>()` directly if you don't need to control
+//! the size of what's generated.
+//!
+//! [proptest]: https://docs.rs/proptest/
+//! [Arbitrary]: https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html
+
+use std::iter::FromIterator;
+
+use ::proptest::arbitrary::{any_with, Arbitrary};
+use ::proptest::collection::{vec, SizeRange};
+use ::proptest::sample::subsequence;
+use ::proptest::strategy::{BoxedStrategy, Just, Strategy};
+use bitmaps::Bits;
+
+use crate::types::ChunkLength;
+use crate::{Chunk, InlineArray, RingBuffer, SparseChunk};
+
+fn checked_size(size: impl Into<SizeRange>, capacity: usize, name: &str) -> SizeRange {
+    let size = size.into();
+    if size.end_incl() > capacity {
+        panic!(
+            "sized_chunks::proptest::{}: size range exceeds capacity",
+            name
+        );
+    }
+    size
+}
+
+/// A strategy for generating a [`Chunk`][Chunk] with a length within `size`,
+/// using `element` to generate its values.
+///
+/// Panics if `size` allows lengths larger than the chunk's capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// # use proptest::prelude::*;
+/// # use sized_chunks::Chunk;
+/// # use sized_chunks::proptest::chunk;
+/// # use typenum::U16;
+/// proptest! {
+///     fn chunk_is_never_overfull(c in chunk::<_, U16>(any::<i32>(), 0..=16)) {
+///         prop_assert!(c.len() <= 16);
+///     }
+/// }
+/// # chunk_is_never_overfull();
+/// ```
+///
+/// [Chunk]: ../struct.Chunk.html
+pub fn chunk<A, N>(
+    element: A,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = Chunk<A::Value, N>>
+where
+    A: Strategy,
+    N: ChunkLength<A::Value>,
+{
+    let size = checked_size(size, Chunk::<A::Value, N>::CAPACITY, "chunk");
+    vec(element, size).prop_map(Chunk::from_iter)
+}
+
+/// A strategy for generating a [`RingBuffer`][RingBuffer] with a length
+/// within `size`, using `element` to generate its values.
+///
+/// The buffer's origin is also randomised, so that generated buffers will
+/// frequently wrap around the end of their backing array.
+///
+/// Panics if `size` allows lengths larger than the buffer's capacity.
+///
+/// [RingBuffer]: ../struct.RingBuffer.html
+pub fn ring_buffer<A, N>(
+    element: A,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = RingBuffer<A::Value, N>>
+where
+    A: Strategy,
+    N: ChunkLength<A::Value>,
+{
+    let capacity = RingBuffer::<A::Value, N>::CAPACITY;
+    let size = checked_size(size, capacity, "ring_buffer");
+    (vec(element, size), 0..capacity.max(1)).prop_map(|(values, origin)| {
+        let mut buffer = RingBuffer::with_origin(origin);
+        buffer.extend(values);
+        buffer
+    })
+}
+
+/// A strategy for generating a [`SparseChunk`][SparseChunk] with a number of
+/// populated indices within `size`, using `element` to generate its values.
+///
+/// The populated indices are chosen uniformly from the chunk's full range.
+///
+/// Panics if `size` allows more values than the chunk's capacity.
+///
+/// [SparseChunk]: ../struct.SparseChunk.html
+pub fn sparse_chunk<A, N>(
+    element: A,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = SparseChunk<A::Value, N>>
+where
+    A: Strategy + Clone,
+    N: Bits + ChunkLength<A::Value>,
+{
+    let capacity = SparseChunk::<A::Value, N>::CAPACITY;
+    let size = checked_size(size, capacity, "sparse_chunk");
+    subsequence((0..capacity).collect::<Vec<_>>(), size)
+        .prop_flat_map(move |indices| {
+            let len = indices.len();
+            (Just(indices), vec(element.clone(), len))
+        })
+        .prop_map(|(indices, values)| {
+            let mut chunk = SparseChunk::new();
+            for (index, value) in indices.into_iter().zip(values) {
+                chunk.insert(index, value);
+            }
+            chunk
+        })
+}
+
+/// A strategy for generating an [`InlineArray`][InlineArray] with a length
+/// within `size`, using `element` to generate its values.
+///
+/// Panics if `size` allows lengths larger than the array's capacity.
+///
+/// [InlineArray]: ../struct.InlineArray.html
+pub fn inline_array<A, T>(
+    element: A,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = InlineArray<A::Value, T>>
+where
+    A: Strategy,
+{
+    let size = checked_size(size, InlineArray::<A::Value, T>::CAPACITY, "inline_array");
+    vec(element, size).prop_map(InlineArray::from_iter)
+}
+
+impl<A, N> Arbitrary for Chunk<A, N>
+where
+    A: Arbitrary + 'static,
+    N: ChunkLength<A> + 'static,
+{
+    type Parameters = A::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        chunk(any_with::<A>(args), 0..=Self::CAPACITY).boxed()
+    }
+}
+
+impl<A, N> Arbitrary for RingBuffer<A, N>
+where
+    A: Arbitrary + 'static,
+    N: ChunkLength<A> + 'static,
+{
+    type Parameters = A::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        ring_buffer(any_with::<A>(args), 0..=Self::CAPACITY).boxed()
+    }
+}
+
+impl<A, N> Arbitrary for SparseChunk<A, N>
+where
+    A: Arbitrary + 'static,
+    N: Bits + ChunkLength<A> + 'static,
+{
+    type Parameters = A::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        sparse_chunk(any_with::<A>(args).boxed(), 0..=Self::CAPACITY).boxed()
+    }
+}
+
+impl<A, T> Arbitrary for InlineArray<A, T>
+where
+    A: Arbitrary + 'static,
+    T: 'static,
+{
+    type Parameters = A::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        inline_array(any_with::<A>(args), 0..=Self::CAPACITY).boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::proptest::{arbitrary::any, prop_assert, prop_assert_eq, proptest};
+    use typenum::{U16, U32};
+
+    proptest! {
+        #[test]
+        fn chunk_respects_size(c in chunk::<_, U16>(any::<u8>(), 3..=9)) {
+            prop_assert!(c.len() >= 3 && c.len() <= 9);
+        }
+
+        #[test]
+        fn ring_buffer_respects_size(buffer in ring_buffer::<_, U16>(any::<u8>(), 0..=16)) {
+            prop_assert!(buffer.len() <= 16);
+            let (left, right) = buffer.slices(0..buffer.len());
+            prop_assert_eq!(buffer.len(), left.len() + right.len());
+        }
+
+        #[test]
+        fn sparse_chunk_respects_size(c in sparse_chunk::<_, U32>(any::<u8>(), 5..10)) {
+            prop_assert!(c.len() >= 5 && c.len() < 10);
+            prop_assert_eq!(c.len(), c.indices().count());
+        }
+
+        #[test]
+        fn inline_array_respects_size(array in any::<InlineArray<u8, [usize; 4]>>()) {
+            prop_assert!(array.len() <= InlineArray::<u8, [usize; 4]>::CAPACITY);
+        }
+    }
+
+    #[test]
+    fn ring_buffer_wraps_around() {
+        use ::proptest::strategy::ValueTree;
+        use ::proptest::test_runner::TestRunner;
+        let strategy = ring_buffer::<_, U16>(any::<u8>(), 16);
+        let mut runner = TestRunner::deterministic();
+        let wrapped = (0..64).any(|_| {
+            let buffer = strategy.new_tree(&mut runner).unwrap().current();
+            !buffer.slices(0..buffer.len()).1.is_empty()
+        });
+        assert!(wrapped);
+    }
+
+    #[test]
+    #[should_panic(expected = "size range exceeds capacity")]
+    fn size_larger_than_capacity() {
+        let _ = chunk::<_, U16>(any::<u8>(), 0..=17);
+    }
+}
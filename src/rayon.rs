@@ -0,0 +1,914 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! [`rayon`][rayon] parallel iterators for the chunk types.
+//!
+//! This module is only available with the `rayon` feature enabled. It holds
+//! the parallel iterator types which aren't simply rayon's own slice
+//! iterators; the trait implementations are on the chunk types themselves:
+//!
+//! * [`Chunk`][Chunk] and [`InlineArray`][InlineArray] can be iterated over in
+//!   parallel by reference, by mutable reference and by value;
+//! * [`RingBuffer`][RingBuffer] can be iterated over by reference, which first
+//!   splits its contents into the two halves either side of the end of its
+//!   storage;
+//! * [`SparseChunk`][SparseChunk] can be iterated over by reference, which
+//!   splits its bitmap between its values.
+//!
+//! `Chunk`, `RingBuffer` and `InlineArray` can also be collected from and
+//! extended by parallel iterators, which will panic if the result would
+//! exceed their capacity.
+//!
+//! [rayon]: https://docs.rs/rayon/
+//! [Chunk]: ../struct.Chunk.html
+//! [InlineArray]: ../struct.InlineArray.html
+//! [RingBuffer]: ../struct.RingBuffer.html
+//! [SparseChunk]: ../struct.SparseChunk.html
+
+use std::collections::LinkedList;
+use std::iter::FusedIterator;
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::slice::{from_raw_parts_mut, IterMut as SliceIterMut};
+
+use ::rayon::iter::plumbing::{
+    bridge, bridge_unindexed, Consumer, Folder, Producer, ProducerCallback, Reducer,
+    UnindexedConsumer, UnindexedProducer,
+};
+use ::rayon::iter::{
+    Chain, FromParallelIterator, IndexedParallelIterator, IntoParallelIterator, ParallelExtend,
+    ParallelIterator,
+};
+use ::rayon::slice::{Iter as ParSliceIter, IterMut as ParSliceIterMut};
+use bitmaps::{Bitmap, Bits};
+
+use crate::sparse_set::Iter as Indices;
+use crate::types::ChunkLength;
+use crate::{Chunk, InlineArray, RingBuffer, SparseChunk};
+
+/// A producer which moves values out of a slice of values nobody else owns
+/// any more, dropping the ones it doesn't get around to producing.
+struct DrainProducer<'a, A> {
+    values: &'a mut [A],
+}
+
+impl<'a, A: Send> Producer for DrainProducer<'a, A> {
+    type Item = A;
+    type IntoIter = SliceDrain<'a, A>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        SliceDrain {
+            iter: mem::take(&mut self.values).iter_mut(),
+        }
+    }
+
+    fn split_at(mut self, index: usize) -> (Self, Self) {
+        let (left, right) = mem::take(&mut self.values).split_at_mut(index);
+        (
+            DrainProducer { values: left },
+            DrainProducer { values: right },
+        )
+    }
+}
+
+impl<'a, A> Drop for DrainProducer<'a, A> {
+    fn drop(&mut self) {
+        let values: *mut [A] = mem::take::<&mut [A]>(&mut self.values);
+        unsafe { ptr::drop_in_place(values) }
+    }
+}
+
+/// The sequential iterator behind a `DrainProducer`.
+struct SliceDrain<'a, A> {
+    iter: SliceIterMut<'a, A>,
+}
+
+impl<'a, A> Iterator for SliceDrain<'a, A> {
+    type Item = A;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|value| unsafe { ptr::read(value) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, A> DoubleEndedIterator for SliceDrain<'a, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|value| unsafe { ptr::read(value) })
+    }
+}
+
+impl<'a, A> ExactSizeIterator for SliceDrain<'a, A> {}
+
+impl<'a, A> FusedIterator for SliceDrain<'a, A> {}
+
+impl<'a, A> Drop for SliceDrain<'a, A> {
+    fn drop(&mut self) {
+        let rest: *mut [A] = mem::replace(&mut self.iter, [].iter_mut()).into_slice();
+        unsafe { ptr::drop_in_place(rest) }
+    }
+}
+
+/// A parallel iterator over the values of a [`Chunk`][Chunk].
+///
+/// [Chunk]: ../struct.Chunk.html
+#[derive(Debug)]
+pub struct ChunkIntoParIter<A, N: ChunkLength<A>> {
+    chunk: Chunk<A, N>,
+}
+
+impl<A, N> ParallelIterator for ChunkIntoParIter<A, N>
+where
+    A: Send,
+    N: ChunkLength<A>,
+    Chunk<A, N>: Send,
+{
+    type Item = A;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.chunk.len())
+    }
+}
+
+impl<A, N> IndexedParallelIterator for ChunkIntoParIter<A, N>
+where
+    A: Send,
+    N: ChunkLength<A>,
+    Chunk<A, N>: Send,
+{
+    fn len(&self) -> usize {
+        self.chunk.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(mut self, callback: CB) -> CB::Output {
+        let values = unsafe { self.chunk.take_values() };
+        callback.callback(DrainProducer { values })
+    }
+}
+
+/// A parallel iterator over the values of an [`InlineArray`][InlineArray].
+///
+/// [InlineArray]: ../struct.InlineArray.html
+#[derive(Debug)]
+pub struct InlineArrayIntoParIter<A, T> {
+    array: InlineArray<A, T>,
+}
+
+impl<A, T> ParallelIterator for InlineArrayIntoParIter<A, T>
+where
+    A: Send,
+    InlineArray<A, T>: Send,
+{
+    type Item = A;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.array.len())
+    }
+}
+
+impl<A, T> IndexedParallelIterator for InlineArrayIntoParIter<A, T>
+where
+    A: Send,
+    InlineArray<A, T>: Send,
+{
+    fn len(&self) -> usize {
+        self.array.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(mut self, callback: CB) -> CB::Output {
+        let len = self.array.len();
+        let values = unsafe {
+            *self.array.len_mut() = 0;
+            from_raw_parts_mut(self.array.data_mut(), len)
+        };
+        callback.callback(DrainProducer { values })
+    }
+}
+
+/// A parallel iterator over references to the values of a
+/// [`SparseChunk`][SparseChunk].
+///
+/// [SparseChunk]: ../struct.SparseChunk.html
+pub struct SparseChunkParIter<'a, A, N: Bits + ChunkLength<A>> {
+    chunk: &'a SparseChunk<A, N>,
+    map: Bitmap<N>,
+}
+
+impl<'a, A, N> ParallelIterator for SparseChunkParIter<'a, A, N>
+where
+    A: Sync,
+    N: Bits + ChunkLength<A>,
+    SparseChunk<A, N>: Sync,
+    Bitmap<N>: Send,
+{
+    type Item = &'a A;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge_unindexed(self, consumer)
+    }
+}
+
+impl<'a, A, N> UnindexedProducer for SparseChunkParIter<'a, A, N>
+where
+    A: Sync,
+    N: Bits + ChunkLength<A>,
+    SparseChunk<A, N>: Sync,
+    Bitmap<N>: Send,
+{
+    type Item = &'a A;
+
+    /// Split the bitmap at the index of the middle value.
+    fn split(self) -> (Self, Option<Self>) {
+        let len = self.map.len();
+        if len < 2 {
+            return (self, None);
+        }
+        let middle = Indices::new(self.map).nth(len / 2).unwrap();
+        let left = self.map & Bitmap::mask(middle);
+        let right = self.map ^ left;
+        (
+            SparseChunkParIter {
+                chunk: self.chunk,
+                map: left,
+            },
+            Some(SparseChunkParIter {
+                chunk: self.chunk,
+                map: right,
+            }),
+        )
+    }
+
+    fn fold_with<F: Folder<Self::Item>>(self, folder: F) -> F {
+        let chunk = self.chunk;
+        folder.consume_iter(Indices::new(self.map).map(move |index| &chunk[index]))
+    }
+}
+
+impl<'a, A, N: Bits + ChunkLength<A>> Clone for SparseChunkParIter<'a, A, N> {
+    fn clone(&self) -> Self {
+        SparseChunkParIter {
+            chunk: self.chunk,
+            map: self.map,
+        }
+    }
+}
+
+impl<'a, A, N: Bits + ChunkLength<A>> std::fmt::Debug for SparseChunkParIter<'a, A, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SparseChunkParIter")
+            .field("indices", &Indices::new(self.map).collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<A, N> IntoParallelIterator for Chunk<A, N>
+where
+    A: Send,
+    N: ChunkLength<A>,
+    Chunk<A, N>: Send,
+{
+    type Item = A;
+    type Iter = ChunkIntoParIter<A, N>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ChunkIntoParIter { chunk: self }
+    }
+}
+
+impl<'a, A: Sync, N: ChunkLength<A>> IntoParallelIterator for &'a Chunk<A, N> {
+    type Item = &'a A;
+    type Iter = ParSliceIter<'a, A>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_slice().into_par_iter()
+    }
+}
+
+impl<'a, A: Send, N: ChunkLength<A>> IntoParallelIterator for &'a mut Chunk<A, N> {
+    type Item = &'a mut A;
+    type Iter = ParSliceIterMut<'a, A>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.as_mut_slice().into_par_iter()
+    }
+}
+
+impl<A, T> IntoParallelIterator for InlineArray<A, T>
+where
+    A: Send,
+    InlineArray<A, T>: Send,
+{
+    type Item = A;
+    type Iter = InlineArrayIntoParIter<A, T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        InlineArrayIntoParIter { array: self }
+    }
+}
+
+impl<'a, A: Sync, T> IntoParallelIterator for &'a InlineArray<A, T> {
+    type Item = &'a A;
+    type Iter = ParSliceIter<'a, A>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        (**self).into_par_iter()
+    }
+}
+
+impl<'a, A: Send, T> IntoParallelIterator for &'a mut InlineArray<A, T> {
+    type Item = &'a mut A;
+    type Iter = ParSliceIterMut<'a, A>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        (**self).into_par_iter()
+    }
+}
+
+impl<'a, A: Sync, N: ChunkLength<A>> IntoParallelIterator for &'a RingBuffer<A, N> {
+    type Item = &'a A;
+    type Iter = Chain<ParSliceIter<'a, A>, ParSliceIter<'a, A>>;
+
+    /// Iterate over the buffer in parallel, starting with a split between the
+    /// values before and after the end of its storage.
+    fn into_par_iter(self) -> Self::Iter {
+        let (left, right) = self.slices(0..self.len());
+        left.into_par_iter().chain(right)
+    }
+}
+
+impl<'a, A, N> IntoParallelIterator for &'a SparseChunk<A, N>
+where
+    A: Sync,
+    N: Bits + ChunkLength<A>,
+    SparseChunk<A, N>: Sync,
+    Bitmap<N>: Send,
+{
+    type Item = &'a A;
+    type Iter = SparseChunkParIter<'a, A, N>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        SparseChunkParIter {
+            chunk: self,
+            map: self.bitmap(),
+        }
+    }
+}
+
+/// The fixed capacity collections a parallel iterator can be collected into.
+trait Collection: Sized {
+    type Item;
+
+    fn empty() -> Self;
+    fn len(&self) -> usize;
+    fn capacity() -> usize;
+    fn push(&mut self, value: Self::Item);
+    fn append(&mut self, other: Self);
+
+    /// Empty the collection and get its storage as uninitialised values.
+    unsafe fn uninit_storage(&mut self) -> &mut [MaybeUninit<Self::Item>];
+
+    /// Claim the first `len` values written through `uninit_storage`.
+    unsafe fn set_len(&mut self, len: usize);
+
+    fn check_capacity(&self, additional: usize, name: &str) {
+        if self.len() + additional > Self::capacity() {
+            panic!("{}: chunk size overflow", name);
+        }
+    }
+}
+
+impl<A, N: ChunkLength<A>> Collection for Chunk<A, N> {
+    type Item = A;
+
+    fn empty() -> Self {
+        Chunk::new()
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn capacity() -> usize {
+        Self::CAPACITY
+    }
+
+    fn push(&mut self, value: A) {
+        self.push_back(value)
+    }
+
+    fn append(&mut self, mut other: Self) {
+        Chunk::append(self, &mut other)
+    }
+
+    unsafe fn uninit_storage(&mut self) -> &mut [MaybeUninit<A>] {
+        Chunk::uninit_storage(self)
+    }
+
+    unsafe fn set_len(&mut self, len: usize) {
+        Chunk::set_len(self, len)
+    }
+}
+
+impl<A, N: ChunkLength<A>> Collection for RingBuffer<A, N> {
+    type Item = A;
+
+    fn empty() -> Self {
+        RingBuffer::new()
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn capacity() -> usize {
+        Self::CAPACITY
+    }
+
+    fn push(&mut self, value: A) {
+        self.push_back(value)
+    }
+
+    fn append(&mut self, mut other: Self) {
+        RingBuffer::append(self, &mut other)
+    }
+
+    unsafe fn uninit_storage(&mut self) -> &mut [MaybeUninit<A>] {
+        RingBuffer::uninit_storage(self)
+    }
+
+    unsafe fn set_len(&mut self, len: usize) {
+        RingBuffer::set_len(self, len)
+    }
+}
+
+impl<A, T> Collection for InlineArray<A, T> {
+    type Item = A;
+
+    fn empty() -> Self {
+        InlineArray::new()
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn capacity() -> usize {
+        Self::CAPACITY
+    }
+
+    fn push(&mut self, value: A) {
+        InlineArray::push(self, value)
+    }
+
+    fn append(&mut self, other: Self) {
+        self.extend(other)
+    }
+
+    unsafe fn uninit_storage(&mut self) -> &mut [MaybeUninit<A>] {
+        *self.len_mut() = 0;
+        from_raw_parts_mut(self.data_mut().cast(), Self::CAPACITY)
+    }
+
+    unsafe fn set_len(&mut self, len: usize) {
+        *self.len_mut() = len;
+    }
+}
+
+/// A consumer for an indexed parallel iterator, which writes each value
+/// straight into its place in the target storage.
+struct CollectConsumer<'a, A> {
+    target: &'a mut [MaybeUninit<A>],
+}
+
+/// The values a `CollectConsumer` has written to the front of its target,
+/// which are dropped again unless they're claimed by forgetting this.
+struct CollectResult<'a, A> {
+    target: &'a mut [MaybeUninit<A>],
+    written: usize,
+}
+
+/// Joins the results of adjacent `CollectConsumer`s.
+struct CollectReducer;
+
+impl<'a, A: Send> Consumer<A> for CollectConsumer<'a, A> {
+    type Folder = CollectResult<'a, A>;
+    type Reducer = CollectReducer;
+    type Result = CollectResult<'a, A>;
+
+    fn split_at(self, index: usize) -> (Self, Self, Self::Reducer) {
+        let (left, right) = self.target.split_at_mut(index);
+        (
+            CollectConsumer { target: left },
+            CollectConsumer { target: right },
+            CollectReducer,
+        )
+    }
+
+    fn into_folder(self) -> Self::Folder {
+        CollectResult {
+            target: self.target,
+            written: 0,
+        }
+    }
+
+    fn full(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, A: Send> UnindexedConsumer<A> for CollectConsumer<'a, A> {
+    fn split_off_left(&self) -> Self {
+        unreachable!("CollectConsumer only collects indexed iterators")
+    }
+
+    fn to_reducer(&self) -> Self::Reducer {
+        CollectReducer
+    }
+}
+
+impl<'a, A> Folder<A> for CollectResult<'a, A> {
+    type Result = Self;
+
+    fn consume(mut self, value: A) -> Self {
+        match self.target.get_mut(self.written) {
+            Some(slot) => *slot = MaybeUninit::new(value),
+            None => panic!("parallel iterator produced more values than it said it would"),
+        }
+        self.written += 1;
+        self
+    }
+
+    fn complete(self) -> Self::Result {
+        self
+    }
+
+    fn full(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, A> Reducer<CollectResult<'a, A>> for CollectReducer {
+    fn reduce(
+        self,
+        mut left: CollectResult<'a, A>,
+        right: CollectResult<'a, A>,
+    ) -> CollectResult<'a, A> {
+        // The two targets were split from one slice, so if the left one is
+        // full, the right one's values continue where it stops.
+        if left.written == left.target.len() {
+            let target = mem::take(&mut left.target);
+            let len = target.len() + right.target.len();
+            left.target = unsafe { from_raw_parts_mut(target.as_mut_ptr(), len) };
+            left.written += right.written;
+            mem::forget(right);
+        }
+        left
+    }
+}
+
+impl<'a, A> Drop for CollectResult<'a, A> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.target.as_mut_ptr().cast::<A>(),
+                self.written,
+            ))
+        }
+    }
+}
+
+/// Fold an unindexed parallel iterator into a list of the `Vec`s each split
+/// collected, in order.
+fn collect_list<I>(par_iter: I) -> LinkedList<Vec<I::Item>>
+where
+    I: ParallelIterator,
+{
+    par_iter
+        .fold(Vec::new, |mut values, value| {
+            values.push(value);
+            values
+        })
+        .map(|values| {
+            let mut list = LinkedList::new();
+            list.push_back(values);
+            list
+        })
+        .reduce(LinkedList::new, |mut left, mut right| {
+            left.append(&mut right);
+            left
+        })
+}
+
+/// Move the values of a list of `Vec`s onto the back of a collection,
+/// panicking with `name` if they won't fit.
+fn push_list<C: Collection>(collection: &mut C, list: LinkedList<Vec<C::Item>>, name: &str) {
+    collection.check_capacity(list.iter().map(Vec::len).sum(), name);
+    for values in list {
+        for value in values {
+            collection.push(value);
+        }
+    }
+}
+
+/// Collect a parallel iterator into a collection, in order, panicking with
+/// `name` as soon as it's clear the values won't fit.
+///
+/// An indexed iterator is written straight into the collection's storage;
+/// otherwise, the values are collected into a `Vec` per split and moved into
+/// the collection once they're all in.
+fn collect<C, I>(par_iter: I, name: &str) -> C
+where
+    C: Collection + Send,
+    C::Item: Send,
+    I: ParallelIterator<Item = C::Item>,
+{
+    let mut collection = C::empty();
+    match par_iter.opt_len() {
+        Some(len) => {
+            collection.check_capacity(len, name);
+            let target = unsafe { &mut collection.uninit_storage()[..len] };
+            let result = par_iter.drive_unindexed(CollectConsumer { target });
+            if result.written != len {
+                panic!(
+                    "{}: expected {} values, but the iterator produced {}",
+                    name, len, result.written
+                );
+            }
+            mem::forget(result);
+            unsafe { collection.set_len(len) };
+        }
+        None => push_list(&mut collection, collect_list(par_iter), name),
+    }
+    collection
+}
+
+/// Extend a collection with a parallel iterator, panicking with `name` if
+/// the values won't fit.
+fn extend<C, I>(collection: &mut C, par_iter: I, name: &str)
+where
+    C: Collection + Send,
+    C::Item: Send,
+    I: ParallelIterator<Item = C::Item>,
+{
+    match par_iter.opt_len() {
+        Some(len) => {
+            collection.check_capacity(len, name);
+            let other: C = collect(par_iter, name);
+            collection.append(other);
+        }
+        None => push_list(collection, collect_list(par_iter), name),
+    }
+}
+
+impl<A, N> FromParallelIterator<A> for Chunk<A, N>
+where
+    A: Send,
+    N: ChunkLength<A>,
+    Chunk<A, N>: Send,
+{
+    /// Collect a parallel iterator into a chunk, keeping the values in order.
+    ///
+    /// Panics if the iterator has more values than the chunk can hold.
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        collect(par_iter.into_par_iter(), "Chunk::from_par_iter")
+    }
+}
+
+impl<A, N> ParallelExtend<A> for Chunk<A, N>
+where
+    A: Send,
+    N: ChunkLength<A>,
+    Chunk<A, N>: Send,
+{
+    /// Append the values of a parallel iterator to the back of the chunk.
+    ///
+    /// Panics if the chunk exceeds its capacity.
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        extend(self, par_iter.into_par_iter(), "Chunk::par_extend")
+    }
+}
+
+impl<A, N> FromParallelIterator<A> for RingBuffer<A, N>
+where
+    A: Send,
+    N: ChunkLength<A>,
+    RingBuffer<A, N>: Send,
+{
+    /// Collect a parallel iterator into a ring buffer, keeping the values in
+    /// order.
+    ///
+    /// Panics if the iterator has more values than the buffer can hold.
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        collect(par_iter.into_par_iter(), "RingBuffer::from_par_iter")
+    }
+}
+
+impl<A, N> ParallelExtend<A> for RingBuffer<A, N>
+where
+    A: Send,
+    N: ChunkLength<A>,
+    RingBuffer<A, N>: Send,
+{
+    /// Append the values of a parallel iterator to the back of the buffer.
+    ///
+    /// Panics if the buffer exceeds its capacity.
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        extend(self, par_iter.into_par_iter(), "RingBuffer::par_extend")
+    }
+}
+
+impl<A, T> FromParallelIterator<A> for InlineArray<A, T>
+where
+    A: Send,
+    InlineArray<A, T>: Send,
+{
+    /// Collect a parallel iterator into an array, keeping the values in order.
+    ///
+    /// Panics if the iterator has more values than the array can hold.
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        collect(par_iter.into_par_iter(), "InlineArray::from_par_iter")
+    }
+}
+
+impl<A, T> ParallelExtend<A> for InlineArray<A, T>
+where
+    A: Send,
+    InlineArray<A, T>: Send,
+{
+    /// Append the values of a parallel iterator to the back of the array.
+    ///
+    /// Panics if the array exceeds its capacity.
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        extend(self, par_iter.into_par_iter(), "InlineArray::par_extend")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tests::{assert_panics, DropTest};
+    use ::rayon::iter::{IntoParallelRefIterator, IntoParallelRefMutIterator};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use typenum::{U128, U64, U8};
+
+    #[test]
+    fn chunk_par_iter() {
+        let mut chunk: Chunk<f64, U128> = (0..128).map(f64::from).collect();
+        chunk.par_iter_mut().for_each(|value| *value *= 2.0);
+        let sum: f64 = chunk.par_iter().sum();
+        assert_eq!(16256.0, sum);
+        let values: Vec<f64> = chunk.into_par_iter().collect();
+        assert_eq!(
+            (0..128).map(|i| f64::from(i) * 2.0).collect::<Vec<_>>(),
+            values
+        );
+    }
+
+    #[test]
+    fn chunk_into_par_iter_drops() {
+        let counter = AtomicUsize::new(0);
+        {
+            let chunk: Chunk<DropTest<'_>, U64> =
+                (0..64).map(|_| DropTest::new(&counter)).collect();
+            let taken = chunk.into_par_iter().with_min_len(4).take(10).count();
+            assert_eq!(10, taken);
+        }
+        assert_eq!(0, counter.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn ring_buffer_par_iter() {
+        let mut buffer: RingBuffer<usize, U64> = (0..64).collect();
+        for value in 64..84 {
+            buffer.pop_front();
+            buffer.push_back(value);
+        }
+        let values: Vec<usize> = buffer.par_iter().cloned().collect();
+        assert_eq!((20..84).collect::<Vec<_>>(), values);
+    }
+
+    #[test]
+    fn sparse_chunk_par_iter() {
+        let mut chunk = SparseChunk::<usize, U128>::new();
+        for index in (0..128).filter(|index| index % 3 == 0) {
+            chunk.insert(index, index * 10);
+        }
+        let mut values: Vec<usize> = chunk.par_iter().cloned().collect();
+        values.sort_unstable();
+        let expected: Vec<usize> = (0..128).filter(|i| i % 3 == 0).map(|i| i * 10).collect();
+        assert_eq!(expected, values);
+        assert_eq!(43, chunk.par_iter().count());
+    }
+
+    #[test]
+    fn inline_array_par_iter() {
+        let array: InlineArray<u32, [u64; 8]> = (0..10).collect();
+        let sum: u32 = (&array).into_par_iter().sum();
+        assert_eq!(45, sum);
+        let values: Vec<u32> = array.into_par_iter().rev().collect();
+        assert_eq!((0..10).rev().collect::<Vec<_>>(), values);
+    }
+
+    #[test]
+    fn collect_and_extend() {
+        let chunk: Chunk<usize, U64> = (0..40usize).into_par_iter().collect();
+        assert_eq!(
+            (0..40).collect::<Vec<_>>(),
+            chunk.iter().cloned().collect::<Vec<_>>()
+        );
+        let mut buffer: RingBuffer<usize, U64> = (0..32usize).into_par_iter().collect();
+        buffer.par_extend((32..64usize).into_par_iter().filter(|_| true));
+        assert_eq!(
+            (0..64).collect::<Vec<_>>(),
+            buffer.iter().cloned().collect::<Vec<_>>()
+        );
+        let mut array: InlineArray<usize, [usize; 8]> = InlineArray::new();
+        array.par_extend(vec![1, 2, 3]);
+        assert_eq!(&[1, 2, 3], array.as_ref());
+    }
+
+    #[test]
+    fn collect_in_place() {
+        let chunk: Chunk<usize, U64> = (0..64usize).into_par_iter().with_max_len(1).collect();
+        assert_eq!((0..64).collect::<Vec<_>>(), chunk.as_slice());
+        let buffer: RingBuffer<usize, U8> = (0..8usize).into_par_iter().rev().collect();
+        assert_eq!(buffer, (0..8).rev().collect::<Vec<_>>());
+        let counter = AtomicUsize::new(0);
+        assert_panics(|| {
+            let _: Chunk<DropTest<'_>, U64> = (0..64usize)
+                .into_par_iter()
+                .with_max_len(1)
+                .map(|index| {
+                    if index == 40 {
+                        panic!("collect_in_place");
+                    }
+                    DropTest::new(&counter)
+                })
+                .collect();
+        });
+        assert_eq!(0, counter.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn collect_overflow() {
+        assert_panics(|| {
+            let _: Chunk<usize, U8> = (0..9usize).into_par_iter().collect();
+        });
+        assert_panics(|| {
+            let _: Chunk<usize, U8> = (0..100usize).into_par_iter().filter(|_| true).collect();
+        });
+        assert_panics(|| {
+            let mut buffer: RingBuffer<usize, U8> = (0..4usize).collect();
+            buffer.par_extend((0..5usize).into_par_iter().filter(|_| true));
+        });
+    }
+}
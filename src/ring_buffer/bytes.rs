@@ -0,0 +1,153 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::io::IoSlice;
+
+use ::bytes::buf::UninitSlice;
+use ::bytes::{Buf, BufMut};
+
+use crate::types::ChunkLength;
+use crate::RingBuffer;
+
+impl<N> Buf for RingBuffer<u8, N>
+where
+    N: ChunkLength<u8>,
+{
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+
+    /// Get the bytes from the front of the buffer up to the end of the buffer
+    /// or the end of its storage, whichever comes first.
+    fn chunk(&self) -> &[u8] {
+        self.slices(0..self.len()).0
+    }
+
+    /// Fill `dst` with the bytes before and after the end of the buffer's
+    /// storage.
+    fn chunks_vectored<'a>(&'a self, dst: &mut [IoSlice<'a>]) -> usize {
+        let (left, right) = self.slices(0..self.len());
+        let mut count = 0;
+        for (slot, slice) in dst.iter_mut().zip([left, right].iter()) {
+            if slice.is_empty() {
+                break;
+            }
+            *slot = IoSlice::new(slice);
+            count += 1;
+        }
+        count
+    }
+
+    /// Discard `count` bytes from the front of the buffer.
+    ///
+    /// Panics if the buffer holds fewer than `count` bytes.
+    ///
+    /// Time: O(1)
+    fn advance(&mut self, count: usize) {
+        if count > self.len() {
+            panic!("RingBuffer::advance: index out of bounds");
+        }
+        self.origin += count;
+        self.length -= count;
+    }
+}
+
+unsafe impl<N> BufMut for RingBuffer<u8, N>
+where
+    N: ChunkLength<u8>,
+{
+    fn remaining_mut(&self) -> usize {
+        Self::CAPACITY - self.len()
+    }
+
+    /// Add `count` bytes written through [`chunk_mut`][chunk_mut] to the back
+    /// of the buffer.
+    ///
+    /// Panics if the buffer doesn't have room for `count` more bytes.
+    ///
+    /// [chunk_mut]: #method.chunk_mut
+    unsafe fn advance_mut(&mut self, count: usize) {
+        if count > self.remaining_mut() {
+            panic!("RingBuffer::advance_mut: chunk size overflow");
+        }
+        self.length += count;
+    }
+
+    /// Get the free space after the back of the buffer, up to the end of its
+    /// storage or the front of the buffer, whichever comes first.
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        if self.is_full() {
+            // This includes a buffer with no capacity, which has no raw index
+            // to point at.
+            return unsafe {
+                UninitSlice::from_raw_parts_mut(&mut self.data as *mut _ as *mut u8, 0)
+            };
+        }
+        if self.is_empty() && Self::CAPACITY > 0 {
+            // Start over from the beginning of the storage, so the free space
+            // doesn't wrap around.
+            self.origin = 0.into();
+        }
+        let end = self.raw(self.len());
+        let free = if end.to_usize() < self.origin.to_usize() {
+            self.origin.to_usize() - end.to_usize()
+        } else {
+            Self::CAPACITY - end.to_usize()
+        };
+        unsafe { UninitSlice::from_raw_parts_mut(self.mut_ptr(end), free) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use typenum::{U0, U8};
+
+    #[test]
+    fn buf_across_wrap() {
+        let mut buffer: RingBuffer<u8, U8> = RingBuffer::with_origin(5);
+        buffer.extend(1..=6);
+        assert_eq!(&[1, 2, 3], buffer.chunk());
+        let mut slices = [IoSlice::new(&[]); 4];
+        assert_eq!(2, buffer.chunks_vectored(&mut slices));
+        assert_eq!(&[4, 5, 6], &*slices[1]);
+        assert_eq!(0x0102, buffer.get_u16());
+        let mut rest = [0; 4];
+        buffer.copy_to_slice(&mut rest);
+        assert_eq!([3, 4, 5, 6], rest);
+        assert!(!buffer.has_remaining());
+    }
+
+    #[test]
+    fn buf_mut_across_wrap() {
+        let mut buffer: RingBuffer<u8, U8> = RingBuffer::with_origin(5);
+        buffer.extend(1..=2);
+        assert_eq!(1, buffer.chunk_mut().len());
+        buffer.put_slice(&[3, 4, 5, 6]);
+        buffer.put_u16(0x0708);
+        assert_eq!(0, buffer.remaining_mut());
+        assert_eq!(0, buffer.chunk_mut().len());
+        assert_eq!(
+            (1..=8).collect::<Vec<u8>>(),
+            buffer.iter().cloned().collect::<Vec<_>>()
+        );
+        buffer.advance(8);
+        assert_eq!(8, buffer.chunk_mut().len());
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut buffer: RingBuffer<u8, U0> = RingBuffer::new();
+        assert_eq!(0, buffer.remaining_mut());
+        assert_eq!(0, buffer.chunk_mut().len());
+        assert_eq!(0, buffer.chunk().len());
+    }
+
+    #[test]
+    #[should_panic(expected = "RingBuffer::advance: index out of bounds")]
+    fn advance_past_end() {
+        let mut buffer: RingBuffer<u8, U8> = (0..4).collect();
+        buffer.advance(5);
+    }
+}
@@ -0,0 +1,299 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::index::RawIndex;
+use super::RingBuffer;
+use crate::types::ChunkLength;
+
+/// A cursor over a `RingBuffer`.
+///
+/// A cursor points either at an element of the buffer, or at a "ghost"
+/// position past the end of the buffer, which sits between the last and the
+/// first elements, so moving past either end of the buffer wraps around
+/// through the ghost position, like the cursors of
+/// `std::collections::LinkedList`.
+///
+/// The cursor keeps track of the raw index of its element, so moving it
+/// around and reading the current element doesn't need to recalculate it.
+pub struct Cursor<'a, A, N>
+where
+    N: ChunkLength<A>,
+{
+    buffer: &'a RingBuffer<A, N>,
+    index: usize,
+    raw: RawIndex<N>,
+}
+
+impl<'a, A, N> Clone for Cursor<'a, A, N>
+where
+    N: ChunkLength<A>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, A, N> Copy for Cursor<'a, A, N> where N: ChunkLength<A> {}
+
+impl<'a, A, N> Cursor<'a, A, N>
+where
+    N: ChunkLength<A>,
+{
+    pub(crate) fn new(buffer: &'a RingBuffer<A, N>) -> Self {
+        Cursor {
+            buffer,
+            index: 0,
+            raw: buffer.origin,
+        }
+    }
+
+    /// Get the index of the cursor's element, or `None` if the cursor is at
+    /// the ghost position.
+    pub fn index(&self) -> Option<usize> {
+        if self.index < self.buffer.len() {
+            Some(self.index)
+        } else {
+            None
+        }
+    }
+
+    /// Get the cursor's element, or `None` if the cursor is at the ghost
+    /// position.
+    pub fn current(&self) -> Option<&'a A> {
+        if self.index < self.buffer.len() {
+            Some(unsafe { &*self.buffer.ptr(self.raw) })
+        } else {
+            None
+        }
+    }
+
+    /// Get the element after the cursor's element.
+    ///
+    /// At the ghost position, this is the first element of the buffer.
+    pub fn peek_next(&self) -> Option<&'a A> {
+        let mut cursor = *self;
+        cursor.move_next();
+        cursor.current()
+    }
+
+    /// Get the element before the cursor's element.
+    ///
+    /// At the ghost position, this is the last element of the buffer.
+    pub fn peek_prev(&self) -> Option<&'a A> {
+        let mut cursor = *self;
+        cursor.move_prev();
+        cursor.current()
+    }
+
+    /// Move the cursor to the next element.
+    ///
+    /// From the last element, this moves to the ghost position, and from the
+    /// ghost position, it moves to the first element.
+    pub fn move_next(&mut self) {
+        if self.index == self.buffer.len() {
+            self.index = 0;
+            self.raw = self.buffer.origin;
+        } else {
+            self.index += 1;
+            let _ = self.raw.inc();
+        }
+    }
+
+    /// Move the cursor to the previous element.
+    ///
+    /// From the first element, this moves to the ghost position, and from the
+    /// ghost position, it moves to the last element.
+    pub fn move_prev(&mut self) {
+        if self.index == 0 {
+            self.index = self.buffer.len();
+            self.raw = self.buffer.raw(self.index);
+        } else {
+            self.index -= 1;
+            let _ = self.raw.dec();
+        }
+    }
+}
+
+/// A cursor over a `RingBuffer` which can edit the buffer at the cursor's
+/// position.
+///
+/// See [`Cursor`][Cursor] for how a cursor moves.
+///
+/// [Cursor]: struct.Cursor.html
+pub struct CursorMut<'a, A, N>
+where
+    N: ChunkLength<A>,
+{
+    buffer: &'a mut RingBuffer<A, N>,
+    index: usize,
+    raw: RawIndex<N>,
+}
+
+impl<'a, A, N> CursorMut<'a, A, N>
+where
+    N: ChunkLength<A>,
+{
+    pub(crate) fn new(buffer: &'a mut RingBuffer<A, N>) -> Self {
+        let raw = buffer.origin;
+        CursorMut {
+            buffer,
+            index: 0,
+            raw,
+        }
+    }
+
+    /// Get a read only cursor at the same position as this one.
+    pub fn as_cursor(&self) -> Cursor<'_, A, N> {
+        Cursor {
+            buffer: self.buffer,
+            index: self.index,
+            raw: self.raw,
+        }
+    }
+
+    /// Get the index of the cursor's element, or `None` if the cursor is at
+    /// the ghost position.
+    pub fn index(&self) -> Option<usize> {
+        self.as_cursor().index()
+    }
+
+    /// Get a mutable reference to the cursor's element, or `None` if the
+    /// cursor is at the ghost position.
+    pub fn current(&mut self) -> Option<&mut A> {
+        self.element(self.index, self.raw)
+    }
+
+    /// Get a mutable reference to the element at an index and its raw index,
+    /// or `None` if that's the ghost position.
+    fn element(&mut self, index: usize, raw: RawIndex<N>) -> Option<&mut A> {
+        if index < self.buffer.len() {
+            Some(unsafe { &mut *self.buffer.mut_ptr(raw) })
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable reference to the element after the cursor's element.
+    ///
+    /// At the ghost position, this is the first element of the buffer.
+    pub fn peek_next(&mut self) -> Option<&mut A> {
+        let mut next = self.as_cursor();
+        next.move_next();
+        let (index, raw) = (next.index, next.raw);
+        self.element(index, raw)
+    }
+
+    /// Get a mutable reference to the element before the cursor's element.
+    ///
+    /// At the ghost position, this is the last element of the buffer.
+    pub fn peek_prev(&mut self) -> Option<&mut A> {
+        let mut prev = self.as_cursor();
+        prev.move_prev();
+        let (index, raw) = (prev.index, prev.raw);
+        self.element(index, raw)
+    }
+
+    /// Move the cursor to the next element.
+    ///
+    /// From the last element, this moves to the ghost position, and from the
+    /// ghost position, it moves to the first element.
+    pub fn move_next(&mut self) {
+        if self.index == self.buffer.len() {
+            self.index = 0;
+            self.raw = self.buffer.origin;
+        } else {
+            self.index += 1;
+            let _ = self.raw.inc();
+        }
+    }
+
+    /// Move the cursor to the previous element.
+    ///
+    /// From the first element, this moves to the ghost position, and from the
+    /// ghost position, it moves to the last element.
+    pub fn move_prev(&mut self) {
+        if self.index == 0 {
+            self.index = self.buffer.len();
+            self.raw = self.buffer.raw(self.index);
+        } else {
+            self.index -= 1;
+            let _ = self.raw.dec();
+        }
+    }
+
+    /// Insert a value before the cursor's element, or at the back of the
+    /// buffer if the cursor is at the ghost position.
+    ///
+    /// The cursor stays on the same element.
+    ///
+    /// Panics if the buffer is full.
+    ///
+    /// Time: O(n) for the number of elements shifted
+    pub fn insert_before(&mut self, value: A) {
+        let origin = self.buffer.origin;
+        self.buffer.insert(self.index, value);
+        self.index += 1;
+        // If the insertion moved the origin, it shifted the values before the
+        // cursor to the left, and the cursor's element stayed put.
+        if self.buffer.origin == origin {
+            let _ = self.raw.inc();
+        }
+    }
+
+    /// Insert a value after the cursor's element, or at the front of the
+    /// buffer if the cursor is at the ghost position.
+    ///
+    /// The cursor stays on the same element.
+    ///
+    /// Panics if the buffer is full.
+    ///
+    /// Time: O(n) for the number of elements shifted
+    pub fn insert_after(&mut self, value: A) {
+        if self.index == self.buffer.len() {
+            // The ghost position's raw index is just past the back of the
+            // buffer, which pushing to the front doesn't move.
+            self.buffer.push_front(value);
+            self.index += 1;
+        } else {
+            let origin = self.buffer.origin;
+            self.buffer.insert(self.index + 1, value);
+            // If the insertion moved the origin, it shifted the cursor's
+            // element to the left along with the values before it.
+            if self.buffer.origin != origin {
+                let _ = self.raw.dec();
+            }
+        }
+    }
+
+    /// Remove the cursor's element and return it, moving the cursor to the
+    /// next element.
+    ///
+    /// Returns `None`, without changing anything, if the cursor is at the
+    /// ghost position.
+    ///
+    /// Time: O(n) for the number of elements shifted
+    pub fn remove_current(&mut self) -> Option<A> {
+        if self.index == self.buffer.len() {
+            return None;
+        }
+        let origin = self.buffer.origin;
+        let value = self.buffer.remove(self.index);
+        // If the removal moved the origin, it shifted the values before the
+        // cursor to the right, so the next element is still one step on.
+        if self.buffer.origin != origin {
+            let _ = self.raw.inc();
+        }
+        Some(value)
+    }
+
+    /// Split the buffer at the cursor, returning a new buffer containing the
+    /// cursor's element and everything after it.
+    ///
+    /// The cursor is left at the ghost position of the original buffer.
+    ///
+    /// Time: O(n) for the number of elements in the new buffer
+    pub fn split_at_cursor(&mut self) -> RingBuffer<A, N> {
+        self.buffer.split_off(self.index)
+    }
+}
@@ -8,19 +8,26 @@ use std::ops::{Add, AddAssign, Sub, SubAssign};
 
 use typenum::Unsigned;
 
+use super::view::{sealed, ViewIndex};
+
 pub(crate) struct RawIndex<N: Unsigned>(usize, PhantomData<N>);
 
 impl<N: Unsigned> Clone for RawIndex<N> {
     #[inline]
-    #[must_use]
     fn clone(&self) -> Self {
-        self.0.into()
+        *self
     }
 }
 
 impl<N> Copy for RawIndex<N> where N: Unsigned {}
 
 impl<N: Unsigned> RawIndex<N> {
+    /// Whether `N` is a power of two, in which case wrapping an index around
+    /// is a bitmask rather than a compare and subtract. This is constant for
+    /// each `N`, so the branch on it compiles away.
+    const POWER_OF_TWO: bool = N::USIZE.is_power_of_two();
+    const MASK: usize = N::USIZE.wrapping_sub(1);
+
     #[inline]
     #[must_use]
     pub(crate) fn to_usize(self) -> usize {
@@ -32,7 +39,9 @@ impl<N: Unsigned> RawIndex<N> {
     #[must_use]
     pub(crate) fn inc(&mut self) -> Self {
         let old = *self;
-        self.0 = if self.0 == N::USIZE - 1 {
+        self.0 = if Self::POWER_OF_TWO {
+            (self.0 + 1) & Self::MASK
+        } else if self.0 == N::USIZE - 1 {
             0
         } else {
             self.0 + 1
@@ -44,7 +53,9 @@ impl<N: Unsigned> RawIndex<N> {
     #[inline]
     #[must_use]
     pub(crate) fn dec(&mut self) -> Self {
-        self.0 = if self.0 == 0 {
+        self.0 = if Self::POWER_OF_TWO {
+            self.0.wrapping_sub(1) & Self::MASK
+        } else if self.0 == 0 {
             N::USIZE - 1
         } else {
             self.0 - 1
@@ -53,18 +64,58 @@ impl<N: Unsigned> RawIndex<N> {
     }
 }
 
+impl<N: Unsigned> sealed::Sealed for RawIndex<N> {}
+
+/// `RingBuffer` edits its storage through a `RingBufferView` indexed with
+/// `RawIndex`, so the view's wrapping uses the same power of two masking as
+/// everything else. The capacity passed in is always `N`, so it's ignored.
+impl<N: Unsigned> ViewIndex for RawIndex<N> {
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        index.into()
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        self.0
+    }
+
+    #[inline]
+    fn next_in(self, _capacity: usize) -> Self {
+        let mut index = self;
+        let _ = index.inc();
+        index
+    }
+
+    #[inline]
+    fn prev_in(self, _capacity: usize) -> Self {
+        let mut index = self;
+        index.dec()
+    }
+
+    #[inline]
+    fn offset_in(self, offset: usize, _capacity: usize) -> Self {
+        self + offset
+    }
+}
+
 impl<N: Unsigned> From<usize> for RawIndex<N> {
     #[inline]
-    #[must_use]
     fn from(index: usize) -> Self {
-        debug_assert!(index < N::USIZE);
+        // A buffer with no capacity still needs an origin, and 0 is the only
+        // sensible one.
+        check!(
+            index < N::USIZE || index == 0,
+            "RawIndex::from: index {} out of bounds for capacity {}",
+            index,
+            N::USIZE
+        );
         RawIndex(index, PhantomData)
     }
 }
 
 impl<N: Unsigned> PartialEq for RawIndex<N> {
     #[inline]
-    #[must_use]
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0
     }
@@ -75,7 +126,6 @@ impl<N: Unsigned> Eq for RawIndex<N> {}
 impl<N: Unsigned> Add for RawIndex<N> {
     type Output = RawIndex<N>;
     #[inline]
-    #[must_use]
     fn add(self, other: Self) -> Self::Output {
         self + other.0
     }
@@ -84,22 +134,25 @@ impl<N: Unsigned> Add for RawIndex<N> {
 impl<N: Unsigned> Add<usize> for RawIndex<N> {
     type Output = RawIndex<N>;
     #[inline]
-    #[must_use]
     fn add(self, other: usize) -> Self::Output {
-        let mut result = self.0 + other;
-        while result >= N::USIZE {
-            result -= N::USIZE;
-        }
-        result.into()
+        let mut result = self;
+        result += other;
+        result
     }
 }
 
 impl<N: Unsigned> AddAssign<usize> for RawIndex<N> {
     #[inline]
     fn add_assign(&mut self, other: usize) {
-        self.0 += other;
-        while self.0 >= N::USIZE {
-            self.0 -= N::USIZE;
+        if Self::POWER_OF_TWO {
+            self.0 = self.0.wrapping_add(other) & Self::MASK;
+        } else {
+            self.0 += other;
+            // With no capacity, the only valid index is 0, and there's nothing
+            // to wrap around.
+            while N::USIZE > 0 && self.0 >= N::USIZE {
+                self.0 -= N::USIZE;
+            }
         }
     }
 }
@@ -107,7 +160,6 @@ impl<N: Unsigned> AddAssign<usize> for RawIndex<N> {
 impl<N: Unsigned> Sub for RawIndex<N> {
     type Output = RawIndex<N>;
     #[inline]
-    #[must_use]
     fn sub(self, other: Self) -> Self::Output {
         self - other.0
     }
@@ -116,23 +168,24 @@ impl<N: Unsigned> Sub for RawIndex<N> {
 impl<N: Unsigned> Sub<usize> for RawIndex<N> {
     type Output = RawIndex<N>;
     #[inline]
-    #[must_use]
     fn sub(self, other: usize) -> Self::Output {
-        let mut start = self.0;
-        while other > start {
-            start += N::USIZE;
-        }
-        (start - other).into()
+        let mut result = self;
+        result -= other;
+        result
     }
 }
 
 impl<N: Unsigned> SubAssign<usize> for RawIndex<N> {
     #[inline]
     fn sub_assign(&mut self, other: usize) {
-        while other > self.0 {
-            self.0 += N::USIZE;
+        if Self::POWER_OF_TWO {
+            self.0 = self.0.wrapping_sub(other) & Self::MASK;
+        } else {
+            while other > self.0 {
+                self.0 += N::USIZE;
+            }
+            self.0 -= other;
         }
-        self.0 -= other;
     }
 }
 
@@ -155,7 +208,6 @@ impl<N: Unsigned> Iterator for IndexIter<N> {
     }
 
     #[inline]
-    #[must_use]
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.remaining, Some(self.remaining))
     }
@@ -176,3 +228,50 @@ impl<N: Unsigned> DoubleEndedIterator for IndexIter<N> {
 impl<N: Unsigned> ExactSizeIterator for IndexIter<N> {}
 
 impl<N: Unsigned> FusedIterator for IndexIter<N> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use typenum::{U7, U8};
+
+    fn walk<N: Unsigned>() -> Vec<usize> {
+        let mut index: RawIndex<N> = 0.into();
+        let mut out = vec![index.to_usize()];
+        let _ = index.dec();
+        out.push(index.to_usize());
+        let _ = index.inc();
+        out.push(index.to_usize());
+        out.push((index + 13).to_usize());
+        out.push((index - 13).to_usize());
+        index += N::USIZE * 2 + 3;
+        out.push(index.to_usize());
+        index -= 5;
+        out.push(index.to_usize());
+        out
+    }
+
+    #[test]
+    fn wrapping_arithmetic() {
+        assert_eq!(vec![0, 7, 0, 5, 3, 3, 6], walk::<U8>());
+        assert_eq!(vec![0, 6, 0, 6, 1, 3, 5], walk::<U7>());
+    }
+
+    #[test]
+    fn view_index_matches_usize() {
+        fn check<N: Unsigned>() {
+            for start in 0..N::USIZE {
+                let index: RawIndex<N> = start.into();
+                assert_eq!(start.next_in(N::USIZE), index.next_in(N::USIZE).to_usize());
+                assert_eq!(start.prev_in(N::USIZE), index.prev_in(N::USIZE).to_usize());
+                for offset in 0..=N::USIZE {
+                    assert_eq!(
+                        start.offset_in(offset, N::USIZE),
+                        index.offset_in(offset, N::USIZE).to_usize()
+                    );
+                }
+            }
+        }
+        check::<U8>();
+        check::<U7>();
+    }
+}
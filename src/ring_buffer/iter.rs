@@ -7,17 +7,27 @@ use std::marker::PhantomData;
 
 use crate::types::ChunkLength;
 
-use super::{index::RawIndex, RingBuffer};
+use super::{RingBuffer, ViewIter, ViewIterMut};
 
 /// A reference iterator over a `RingBuffer`.
 pub struct Iter<'a, A, N>
 where
     N: ChunkLength<A>,
 {
-    pub(crate) buffer: &'a RingBuffer<A, N>,
-    pub(crate) left_index: RawIndex<N>,
-    pub(crate) right_index: RawIndex<N>,
-    pub(crate) remaining: usize,
+    iter: ViewIter<'a, A>,
+    capacity: PhantomData<N>,
+}
+
+impl<'a, A, N> Iter<'a, A, N>
+where
+    N: ChunkLength<A>,
+{
+    pub(crate) fn new(iter: ViewIter<'a, A>) -> Self {
+        Self {
+            iter,
+            capacity: PhantomData,
+        }
+    }
 }
 
 impl<'a, A, N> Iterator for Iter<'a, A, N>
@@ -26,19 +36,14 @@ where
 {
     type Item = &'a A;
 
+    #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining == 0 {
-            None
-        } else {
-            self.remaining -= 1;
-            Some(unsafe { &*self.buffer.ptr(self.left_index.inc()) })
-        }
+        self.iter.next()
     }
 
     #[inline]
-    #[must_use]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.remaining, Some(self.remaining))
+        self.iter.size_hint()
     }
 }
 
@@ -46,13 +51,9 @@ impl<'a, A, N> DoubleEndedIterator for Iter<'a, A, N>
 where
     N: ChunkLength<A>,
 {
+    #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.remaining == 0 {
-            None
-        } else {
-            self.remaining -= 1;
-            Some(unsafe { &*self.buffer.ptr(self.right_index.dec()) })
-        }
+        self.iter.next_back()
     }
 }
 
@@ -65,94 +66,52 @@ pub struct IterMut<'a, A, N>
 where
     N: ChunkLength<A>,
 {
-    data: *mut A,
-    left_index: RawIndex<N>,
-    right_index: RawIndex<N>,
-    remaining: usize,
-    phantom: PhantomData<&'a ()>,
+    iter: ViewIterMut<'a, A>,
+    capacity: PhantomData<N>,
 }
 
 impl<'a, A, N> IterMut<'a, A, N>
 where
     N: ChunkLength<A>,
-    A: 'a,
 {
-    pub(crate) fn new(buffer: &mut RingBuffer<A, N>) -> Self {
-        Self::new_slice(buffer, buffer.origin, buffer.len())
-    }
-
-    pub(crate) fn new_slice(
-        buffer: &mut RingBuffer<A, N>,
-        origin: RawIndex<N>,
-        len: usize,
-    ) -> Self {
+    pub(crate) fn new(iter: ViewIterMut<'a, A>) -> Self {
         Self {
-            left_index: origin,
-            right_index: origin + len,
-            remaining: len,
-            phantom: PhantomData,
-            data: buffer.data.as_mut_ptr().cast(),
+            iter,
+            capacity: PhantomData,
         }
     }
-
-    unsafe fn mut_ptr(&mut self, index: RawIndex<N>) -> *mut A {
-        self.data.add(index.to_usize())
-    }
 }
 
 impl<'a, A, N> Iterator for IterMut<'a, A, N>
 where
     N: ChunkLength<A>,
-    A: 'a,
 {
     type Item = &'a mut A;
 
+    #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining == 0 {
-            None
-        } else {
-            self.remaining -= 1;
-            let index = self.left_index.inc();
-            Some(unsafe { &mut *self.mut_ptr(index) })
-        }
+        self.iter.next()
     }
 
     #[inline]
-    #[must_use]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.remaining, Some(self.remaining))
+        self.iter.size_hint()
     }
 }
 
 impl<'a, A, N> DoubleEndedIterator for IterMut<'a, A, N>
 where
     N: ChunkLength<A>,
-    A: 'a,
 {
+    #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.remaining == 0 {
-            None
-        } else {
-            self.remaining -= 1;
-            let index = self.right_index.dec();
-            Some(unsafe { &mut *self.mut_ptr(index) })
-        }
+        self.iter.next_back()
     }
 }
 
-impl<'a, A, N> ExactSizeIterator for IterMut<'a, A, N>
-where
-    N: ChunkLength<A>,
-    A: 'a,
-{
-}
+impl<'a, A, N> ExactSizeIterator for IterMut<'a, A, N> where N: ChunkLength<A> {}
 
-impl<'a, A, N> FusedIterator for IterMut<'a, A, N>
-where
-    N: ChunkLength<A>,
-    A: 'a,
-{
-}
+impl<'a, A, N> FusedIterator for IterMut<'a, A, N> where N: ChunkLength<A> {}
 
 /// A draining iterator over a `RingBuffer`.
 pub struct Drain<'a, A, N: ChunkLength<A>> {
@@ -168,7 +127,6 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> Iterator for Drain<'a, A, N> {
     }
 
     #[inline]
-    #[must_use]
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.buffer.len(), Some(self.buffer.len()))
     }
@@ -199,7 +157,6 @@ impl<A, N: ChunkLength<A>> Iterator for OwnedIter<A, N> {
     }
 
     #[inline]
-    #[must_use]
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.buffer.len(), Some(self.buffer.len()))
     }
@@ -8,29 +8,46 @@
 
 use std::borrow::Borrow;
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::fmt::{Debug, Error, Formatter};
 use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
-use std::mem::MaybeUninit;
-use std::ops::{Bound, Range, RangeBounds};
-use std::ops::{Index, IndexMut};
+use std::mem::{self, ManuallyDrop, MaybeUninit};
+use std::ops::{Add, Index, IndexMut, Sub};
+use std::ops::{Range, RangeBounds};
 
-use typenum::U64;
+use typenum::{Diff, Sum, U64};
 
-use crate::types::ChunkLength;
+use crate::inline_array::InlineArray;
+use crate::sized_chunk::Chunk;
+use crate::types::{ChunkLength, DoubleEndedFixedSeq, FixedSeq};
 
 mod index;
 use index::{IndexIter, RawIndex};
 
+mod cursor;
+pub use cursor::{Cursor, CursorMut};
+
 mod iter;
 pub use iter::{Drain, Iter, IterMut, OwnedIter};
 
 mod slice;
 pub use slice::{Slice, SliceMut};
 
+mod view;
+pub use view::{
+    RingBufferView, RingBufferViewState, ViewIndex, ViewIter, ViewIterMut, ViewSlice, ViewSliceMut,
+};
+
 #[cfg(feature = "refpool")]
 mod refpool;
 
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+#[cfg(feature = "bytes")]
+mod bytes;
+
 /// A fixed capacity ring buffer.
 ///
 /// A ring buffer is an array where the first logical index is at some arbitrary
@@ -72,6 +89,8 @@ impl<A, N: ChunkLength<A>> Drop for RingBuffer<A, N> {
                 unsafe { self.force_drop(i) }
             }
         }
+        #[cfg(feature = "zeroize")]
+        crate::types::zeroize_storage(&mut self.data);
     }
 }
 
@@ -90,16 +109,35 @@ where
 
     #[inline]
     unsafe fn ptr(&self, index: RawIndex<N>) -> *const A {
-        debug_assert!(index.to_usize() < Self::CAPACITY);
+        check!(
+            index.to_usize() < Self::CAPACITY,
+            "RingBuffer::ptr: raw index out of bounds"
+        );
         (&self.data as *const _ as *const A).add(index.to_usize())
     }
 
     #[inline]
     unsafe fn mut_ptr(&mut self, index: RawIndex<N>) -> *mut A {
-        debug_assert!(index.to_usize() < Self::CAPACITY);
+        check!(
+            index.to_usize() < Self::CAPACITY,
+            "RingBuffer::mut_ptr: raw index out of bounds"
+        );
         (&mut self.data as *mut _ as *mut A).add(index.to_usize())
     }
 
+    /// Get a view of the buffer's storage, for sharing the implementation of
+    /// editing operations with `RingBufferView`.
+    #[inline]
+    fn view(&mut self) -> RingBufferView<'_, A, RawIndex<N>> {
+        let data = unsafe {
+            std::slice::from_raw_parts_mut(
+                &mut self.data as *mut _ as *mut MaybeUninit<A>,
+                N::USIZE,
+            )
+        };
+        unsafe { RingBufferView::from_raw(&mut self.origin, &mut self.length, data) }
+    }
+
     /// Drop the value at a raw index.
     #[inline]
     unsafe fn force_drop(&mut self, index: RawIndex<N>) {
@@ -126,6 +164,11 @@ where
         to: RawIndex<N>,
         count: usize,
     ) {
+        check!(
+            count <= Self::CAPACITY,
+            "RingBuffer::copy_from: chunk size overflow"
+        );
+
         #[inline]
         unsafe fn force_copy_to<A, N: ChunkLength<A>>(
             source: &mut RingBuffer<A, N>,
@@ -135,8 +178,14 @@ where
             count: usize,
         ) {
             if count > 0 {
-                debug_assert!(from.to_usize() + count <= RingBuffer::<A, N>::CAPACITY);
-                debug_assert!(to.to_usize() + count <= RingBuffer::<A, N>::CAPACITY);
+                check!(
+                    from.to_usize() + count <= RingBuffer::<A, N>::CAPACITY,
+                    "RingBuffer::copy_from: source range out of bounds"
+                );
+                check!(
+                    to.to_usize() + count <= RingBuffer::<A, N>::CAPACITY,
+                    "RingBuffer::copy_from: target range out of bounds"
+                );
                 std::ptr::copy_nonoverlapping(source.mut_ptr(from), target.mut_ptr(to), count)
             }
         }
@@ -159,7 +208,10 @@ where
     /// Copy values from a slice.
     unsafe fn copy_from_slice(&mut self, source: &[A], to: RawIndex<N>) {
         let count = source.len();
-        debug_assert!(to.to_usize() + count <= Self::CAPACITY);
+        check!(
+            count <= Self::CAPACITY,
+            "RingBuffer::copy_from_slice: chunk size overflow"
+        );
         if to.to_usize() + count > Self::CAPACITY {
             let first_length = Self::CAPACITY - to.to_usize();
             let first_slice = &source[..first_length];
@@ -189,6 +241,60 @@ where
         }
     }
 
+    /// Get the buffer's contents as a `ViewSlice`.
+    #[inline]
+    fn as_view_slice(&self) -> ViewSlice<'_, A> {
+        unsafe {
+            view::split_storage(
+                self.data.as_ptr().cast(),
+                Self::CAPACITY,
+                self.origin.to_usize(),
+                self.len(),
+            )
+        }
+    }
+
+    /// Get the buffer's contents as a `ViewSliceMut`.
+    #[inline]
+    fn as_view_slice_mut(&mut self) -> ViewSliceMut<'_, A> {
+        unsafe {
+            view::split_storage_mut(
+                self.data.as_mut_ptr().cast(),
+                Self::CAPACITY,
+                self.origin.to_usize(),
+                self.len(),
+            )
+        }
+    }
+
+    /// Get the values in a logical range as a pair of contiguous slices.
+    ///
+    /// The second slice will be empty unless the range wraps around the end of
+    /// the buffer.
+    pub(crate) fn slices(&self, range: Range<usize>) -> (&[A], &[A]) {
+        check!(
+            range.start <= range.end && range.end <= self.len(),
+            "RingBuffer::slices: range out of bounds"
+        );
+        self.as_view_slice().slice(range).as_slices()
+    }
+
+    /// Empty the buffer and get its whole storage as uninitialised values, to
+    /// be written from the front and then claimed with `set_len`.
+    #[cfg(feature = "rayon")]
+    pub(crate) unsafe fn uninit_storage(&mut self) -> &mut [MaybeUninit<A>] {
+        self.origin = 0.into();
+        self.length = 0;
+        std::slice::from_raw_parts_mut(self.data.as_mut_ptr().cast(), Self::CAPACITY)
+    }
+
+    /// Claim the first `len` values written through `uninit_storage`.
+    #[cfg(feature = "rayon")]
+    pub(crate) unsafe fn set_len(&mut self, len: usize) {
+        self.origin = 0.into();
+        self.length = len;
+    }
+
     /// Construct an empty ring buffer.
     #[inline]
     #[must_use]
@@ -200,6 +306,72 @@ where
         }
     }
 
+    /// Construct a new empty buffer in place.
+    ///
+    /// This only initialises the buffer's bookkeeping, leaving its storage
+    /// untouched, so it's cheap however large the buffer is, and the buffer
+    /// never needs to exist on the stack.
+    pub fn init_in_place(target: &mut MaybeUninit<Self>) -> &mut Self {
+        unsafe {
+            let ptr = target.as_mut_ptr();
+            std::ptr::addr_of_mut!((*ptr).origin).write(0.into());
+            std::ptr::addr_of_mut!((*ptr).length).write(0);
+            &mut *ptr
+        }
+    }
+
+    /// Construct a new empty buffer directly on the heap.
+    ///
+    /// Unlike `Box::new(RingBuffer::new())`, this never builds the buffer on the
+    /// stack first, so it's safe to use for buffers too large to fit there.
+    pub fn new_boxed() -> Box<Self> {
+        let mut boxed = crate::boxed::new_uninit::<Self>();
+        Self::init_in_place(&mut boxed);
+        unsafe { crate::boxed::assume_init(boxed) }
+    }
+
+    /// Clone the buffer directly onto the heap.
+    ///
+    /// See [`new_boxed`](#method.new_boxed).
+    pub fn clone_boxed(&self) -> Box<Self>
+    where
+        A: Clone,
+    {
+        let mut out = Self::new_boxed();
+        for value in self.iter() {
+            out.push_back(value.clone());
+        }
+        out
+    }
+
+    /// Construct a buffer directly on the heap from an iterator.
+    ///
+    /// See [`new_boxed`](#method.new_boxed).
+    ///
+    /// Panics if the iterator yields more values than the buffer can hold.
+    pub fn collect_boxed<I>(iter: I) -> Box<Self>
+    where
+        I: IntoIterator<Item = A>,
+    {
+        let mut out = Self::new_boxed();
+        out.extend(iter);
+        out
+    }
+
+    /// Construct an empty ring buffer which will start writing at raw index
+    /// `origin` rather than at the start of its backing array.
+    pub(crate) fn with_origin(origin: usize) -> Self {
+        check!(
+            origin < N::USIZE || origin == 0,
+            "RingBuffer::with_origin: origin out of bounds"
+        );
+        Self {
+            origin: origin.into(),
+            length: 0,
+            data: MaybeUninit::uninit(),
+        }
+    }
+
     /// Construct a ring buffer with a single item.
     #[inline]
     #[must_use]
@@ -307,12 +479,7 @@ where
     #[inline]
     #[must_use]
     pub fn iter(&self) -> Iter<'_, A, N> {
-        Iter {
-            buffer: self,
-            left_index: self.origin,
-            right_index: self.origin + self.len(),
-            remaining: self.len(),
-        }
+        Iter::new(self.as_view_slice().iter())
     }
 
     /// Get an iterator over mutable references to the items in the ring buffer
@@ -320,45 +487,34 @@ where
     #[inline]
     #[must_use]
     pub fn iter_mut(&mut self) -> IterMut<'_, A, N> {
-        IterMut::new(self)
+        IterMut::new(self.as_view_slice_mut().into_iter())
     }
 
+    /// Get a cursor pointing at the first item in the ring buffer.
     #[must_use]
-    fn parse_range<R: RangeBounds<usize>>(&self, range: R) -> Range<usize> {
-        let new_range = Range {
-            start: match range.start_bound() {
-                Bound::Unbounded => 0,
-                Bound::Included(index) => *index,
-                Bound::Excluded(_) => unimplemented!(),
-            },
-            end: match range.end_bound() {
-                Bound::Unbounded => self.len(),
-                Bound::Included(index) => *index + 1,
-                Bound::Excluded(index) => *index,
-            },
-        };
-        if new_range.end > self.len() || new_range.start > new_range.end {
-            panic!("Slice::parse_range: index out of bounds");
-        }
-        new_range
+    pub fn cursor(&self) -> Cursor<'_, A, N> {
+        Cursor::new(self)
+    }
+
+    /// Get a cursor pointing at the first item in the ring buffer, which can
+    /// insert and remove items at its position.
+    #[must_use]
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, A, N> {
+        CursorMut::new(self)
     }
 
     /// Get a `Slice` for a subset of the ring buffer.
     #[must_use]
     pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Slice<'_, A, N> {
-        Slice {
-            buffer: self,
-            range: self.parse_range(range),
-        }
+        let range = view::parse_range(range, self.len(), "RingBuffer::slice");
+        Slice::new(self.as_view_slice().slice(range))
     }
 
     /// Get a `SliceMut` for a subset of the ring buffer.
     #[must_use]
     pub fn slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> SliceMut<'_, A, N> {
-        SliceMut {
-            range: self.parse_range(range),
-            buffer: self,
-        }
+        let range = view::parse_range(range, self.len(), "RingBuffer::slice_mut");
+        SliceMut::new(self.as_view_slice_mut().slice(range))
     }
 
     /// Get a reference to the value at a given index.
@@ -445,10 +601,8 @@ where
     pub fn push_back(&mut self, value: A) {
         if self.is_full() {
             panic!("RingBuffer::push_back: can't push to a full buffer")
-        } else {
-            unsafe { self.force_write(self.raw(self.length), value) }
-            self.length += 1;
         }
+        self.view().push_back(value)
     }
 
     /// Push a value to the front of the buffer.
@@ -459,11 +613,8 @@ where
     pub fn push_front(&mut self, value: A) {
         if self.is_full() {
             panic!("RingBuffer::push_front: can't push to a full buffer")
-        } else {
-            let origin = self.origin.dec();
-            self.length += 1;
-            unsafe { self.force_write(origin, value) }
         }
+        self.view().push_front(value)
     }
 
     /// Pop a value from the back of the buffer.
@@ -472,12 +623,7 @@ where
     ///
     /// Time: O(1)
     pub fn pop_back(&mut self) -> Option<A> {
-        if self.is_empty() {
-            None
-        } else {
-            self.length -= 1;
-            Some(unsafe { self.force_read(self.raw(self.length)) })
-        }
+        self.view().pop_back()
     }
 
     /// Pop a value from the front of the buffer.
@@ -486,13 +632,7 @@ where
     ///
     /// Time: O(1)
     pub fn pop_front(&mut self) -> Option<A> {
-        if self.is_empty() {
-            None
-        } else {
-            self.length -= 1;
-            let index = self.origin.inc();
-            Some(unsafe { self.force_read(index) })
-        }
+        self.view().pop_front()
     }
 
     /// Discard all items up to but not including `index`.
@@ -505,11 +645,12 @@ where
             if index > self.len() {
                 panic!("RingBuffer::drop_left: index out of bounds");
             }
-            for i in self.range().take(index) {
-                unsafe { self.force_drop(i) }
-            }
+            let dropped = self.range().take(index);
             self.origin += index;
             self.length -= index;
+            for i in dropped {
+                unsafe { self.force_drop(i) }
+            }
         }
     }
 
@@ -525,10 +666,11 @@ where
         if index == self.len() {
             return;
         }
-        for i in self.range().skip(index) {
+        let dropped = self.range().skip(index);
+        self.length = index;
+        for i in dropped {
             unsafe { self.force_drop(i) }
         }
-        self.length = index;
     }
 
     /// Split a buffer into two, the original buffer containing
@@ -554,6 +696,92 @@ where
         right
     }
 
+    /// Move the contents of the buffer onto the back of a buffer of another
+    /// size.
+    ///
+    /// `target` must have room for every value in the buffer.
+    unsafe fn move_to_back<M>(mut self, target: &mut RingBuffer<A, M>)
+    where
+        M: ChunkLength<A>,
+    {
+        let (left, right) = self.slices(0..self.len());
+        for slice in [left, right] {
+            if !slice.is_empty() {
+                target.copy_from_slice(slice, target.raw(target.length));
+                target.length += slice.len();
+            }
+        }
+        self.length = 0;
+    }
+
+    /// Concatenate two buffers into a buffer big enough to hold both of them.
+    ///
+    /// Time: O(n) for the number of items moved
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::iter::FromIterator;
+    /// # use sized_chunks::RingBuffer;
+    /// # use typenum::{U3, U4, U7};
+    /// let left = RingBuffer::<i32, U3>::from_iter(0..3);
+    /// let right = RingBuffer::<i32, U4>::from_iter(3..5);
+    /// let buffer: RingBuffer<i32, U7> = left.concat(right);
+    /// assert_eq!(buffer, vec![0, 1, 2, 3, 4]);
+    /// ```
+    #[must_use]
+    pub fn concat<M>(self, other: RingBuffer<A, M>) -> RingBuffer<A, Sum<N, M>>
+    where
+        M: ChunkLength<A>,
+        N: Add<M>,
+        Sum<N, M>: ChunkLength<A>,
+    {
+        let mut out = RingBuffer::new();
+        unsafe {
+            self.move_to_back(&mut out);
+            other.move_to_back(&mut out);
+        }
+        out
+    }
+
+    /// Split a buffer into a buffer of capacity `K` holding its first `K`
+    /// items and a buffer holding whatever's left over.
+    ///
+    /// Time: O(n) for the number of items moved
+    #[must_use]
+    pub fn split_at_type<K>(mut self) -> (RingBuffer<A, K>, RingBuffer<A, Diff<N, K>>)
+    where
+        K: ChunkLength<A>,
+        N: Sub<K>,
+        Diff<N, K>: ChunkLength<A>,
+    {
+        let right = self.split_off(K::USIZE.min(self.len()));
+        let mut left_out = RingBuffer::new();
+        let mut right_out = RingBuffer::new();
+        unsafe {
+            self.move_to_back(&mut left_out);
+            right.move_to_back(&mut right_out);
+        }
+        (left_out, right_out)
+    }
+
+    /// Move the contents of the buffer into a buffer of capacity `M`.
+    ///
+    /// Returns the buffer unchanged as an error if it has more than `M` items.
+    ///
+    /// Time: O(n) for the number of items moved
+    pub fn resize_capacity<M>(self) -> Result<RingBuffer<A, M>, Self>
+    where
+        M: ChunkLength<A>,
+    {
+        if self.len() > M::USIZE {
+            return Err(self);
+        }
+        let mut out = RingBuffer::new();
+        unsafe { self.move_to_back(&mut out) };
+        Ok(out)
+    }
+
     /// Remove all items from `other` and append them to the back of `self`.
     ///
     /// Panics if the capacity of `self` is exceeded.
@@ -611,6 +839,69 @@ where
         self.length += count;
     }
 
+    /// Move items between `self` and its right sibling `other` until their
+    /// lengths differ by at most one.
+    ///
+    /// The items keep their order, reading from the front of `self` to the
+    /// back of `other`.
+    ///
+    /// Time: O(n) for the number of items moved
+    pub fn balance_with(&mut self, other: &mut Self) {
+        let self_len = self.len();
+        let other_len = other.len();
+        if self_len > other_len + 1 {
+            other.drain_from_back(self, (self_len - other_len) / 2);
+        } else if other_len > self_len + 1 {
+            self.drain_from_front(other, (other_len - self_len) / 2);
+        }
+    }
+
+    /// Append all items from `other` to the back of `self` if they fit,
+    /// leaving `other` empty.
+    ///
+    /// Returns `false`, leaving both untouched, if they don't fit.
+    ///
+    /// Time: O(n) for the number of items moved
+    pub fn merge_if_fits(&mut self, other: &mut Self) -> bool {
+        if self.len() + other.len() > N::USIZE {
+            false
+        } else {
+            self.append(other);
+            true
+        }
+    }
+
+    /// Move `count` items from the back of `siblings[index - 1]` to the front
+    /// of `siblings[index]`.
+    ///
+    /// Panics if `index` is zero or out of bounds, if the left sibling has
+    /// fewer than `count` items, or if `siblings[index]` doesn't have room for
+    /// them.
+    ///
+    /// Time: O(n) for the number of items moved
+    pub fn steal_from_left(siblings: &mut [Self], index: usize, count: usize) {
+        if index == 0 || index >= siblings.len() {
+            panic!("RingBuffer::steal_from_left: index out of bounds");
+        }
+        let (left, right) = siblings.split_at_mut(index);
+        right[0].drain_from_back(&mut left[index - 1], count);
+    }
+
+    /// Move `count` items from the front of `siblings[index + 1]` to the back
+    /// of `siblings[index]`.
+    ///
+    /// Panics if `siblings[index + 1]` is out of bounds, if it has fewer than
+    /// `count` items, or if `siblings[index]` doesn't have room for them.
+    ///
+    /// Time: O(n) for the number of items moved
+    pub fn steal_from_right(siblings: &mut [Self], index: usize, count: usize) {
+        if index + 1 >= siblings.len() {
+            panic!("RingBuffer::steal_from_right: index out of bounds");
+        }
+        let (left, right) = siblings.split_at_mut(index + 1);
+        left[index].drain_from_front(&mut right[0], count);
+    }
+
     /// Update the value at index `index`, returning the old value.
     ///
     /// Panics if `index` is out of bounds.
@@ -633,33 +924,7 @@ where
         if index > self.len() {
             panic!("RingBuffer::insert: index out of bounds");
         }
-        if index == 0 {
-            return self.push_front(value);
-        }
-        if index == self.len() {
-            return self.push_back(value);
-        }
-        let right_count = self.len() - index;
-        // Check which side has fewer elements to shift.
-        if right_count < index {
-            // Shift to the right.
-            let mut i = self.raw(self.len() - 1);
-            let target = self.raw(index);
-            while i != target {
-                unsafe { self.force_write(i + 1, self.force_read(i)) };
-                i -= 1;
-            }
-            unsafe { self.force_write(target + 1, self.force_read(target)) };
-            self.length += 1;
-        } else {
-            // Shift to the left.
-            self.origin -= 1;
-            self.length += 1;
-            for i in self.range().take(index) {
-                unsafe { self.force_write(i, self.force_read(i + 1)) };
-            }
-        }
-        unsafe { self.force_write(self.raw(index), value) };
+        self.view().insert(index, value)
     }
 
     /// Insert a new value into the buffer in sorted order.
@@ -743,10 +1008,17 @@ where
                 unsafe { self.force_write(i, self.force_read(i + insert_size)) };
             }
         }
-        let mut index = self.raw(index);
-        for value in iter {
-            unsafe { self.force_write(index, value) };
-            index += 1;
+        let mut gap = InsertGap {
+            next: self.raw(index),
+            index,
+            remaining: insert_size,
+            buffer: self,
+        };
+        for value in iter.take(insert_size) {
+            unsafe { gap.buffer.force_write(gap.next, value) };
+            let _ = gap.next.inc();
+            gap.index += 1;
+            gap.remaining -= 1;
         }
     }
 
@@ -762,29 +1034,7 @@ where
         if index >= self.len() {
             panic!("RingBuffer::remove: index out of bounds");
         }
-        let value = unsafe { self.force_read(self.raw(index)) };
-        let right_count = self.len() - index;
-        // Check which side has fewer elements to shift.
-        if right_count < index {
-            // Shift from the right.
-            self.length -= 1;
-            let mut i = self.raw(index);
-            let target = self.raw(self.len());
-            while i != target {
-                unsafe { self.force_write(i, self.force_read(i + 1)) };
-                i += 1;
-            }
-        } else {
-            // Shift from the left.
-            let mut i = self.raw(index);
-            while i != self.origin {
-                unsafe { self.force_write(i, self.force_read(i - 1)) };
-                i -= 1;
-            }
-            self.origin += 1;
-            self.length -= 1;
-        }
-        value
+        self.view().remove(index)
     }
 
     /// Construct an iterator that drains values from the front of the buffer.
@@ -796,17 +1046,45 @@ where
     ///
     /// Time: O(n)
     pub fn clear(&mut self) {
-        for i in self.range() {
-            unsafe { self.force_drop(i) };
-        }
+        let dropped = self.range();
         self.origin = 0.into();
         self.length = 0;
+        for i in dropped {
+            unsafe { self.force_drop(i) };
+        }
+    }
+}
+
+/// The gap `RingBuffer::insert_from` opens for the values it's inserting.
+///
+/// Dropping it closes whatever's left of the gap, so the buffer stays valid
+/// even if the iterator panics or yields fewer values than it said it would.
+struct InsertGap<'a, A, N: ChunkLength<A>> {
+    buffer: &'a mut RingBuffer<A, N>,
+    index: usize,
+    next: RawIndex<N>,
+    remaining: usize,
+}
+
+impl<'a, A, N: ChunkLength<A>> Drop for InsertGap<'a, A, N> {
+    fn drop(&mut self) {
+        if self.remaining > 0 {
+            let tail = self.buffer.len() - self.index - self.remaining;
+            for offset in 0..tail {
+                unsafe {
+                    let value = self
+                        .buffer
+                        .force_read(self.next + (self.remaining + offset));
+                    self.buffer.force_write(self.next + offset, value);
+                }
+            }
+            self.buffer.length -= self.remaining;
+        }
     }
 }
 
 impl<A, N: ChunkLength<A>> Default for RingBuffer<A, N> {
     #[inline]
-    #[must_use]
     fn default() -> Self {
         Self::new()
     }
@@ -814,11 +1092,11 @@ impl<A, N: ChunkLength<A>> Default for RingBuffer<A, N> {
 
 impl<A: Clone, N: ChunkLength<A>> Clone for RingBuffer<A, N> {
     fn clone(&self) -> Self {
-        let mut out = Self::new();
-        out.origin = self.origin;
-        out.length = self.length;
-        for index in out.range() {
-            unsafe { out.force_write(index, (&*self.ptr(index)).clone()) };
+        // Cloning into the same raw positions one value at a time means that
+        // if a clone panics, `out` only owns the values cloned so far.
+        let mut out = Self::with_origin(self.origin.to_usize());
+        for value in self.iter() {
+            out.push_back(value.clone());
         }
         out
     }
@@ -830,7 +1108,6 @@ where
 {
     type Output = A;
 
-    #[must_use]
     fn index(&self, index: usize) -> &Self::Output {
         if index >= self.len() {
             panic!(
@@ -847,7 +1124,6 @@ impl<A, N> IndexMut<usize> for RingBuffer<A, N>
 where
     N: ChunkLength<A>,
 {
-    #[must_use]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
         if index >= self.len() {
             panic!(
@@ -860,10 +1136,11 @@ where
     }
 }
 
-impl<A: PartialEq, N: ChunkLength<A>> PartialEq for RingBuffer<A, N> {
+impl<A: PartialEq, N: ChunkLength<A>, M: ChunkLength<A>> PartialEq<RingBuffer<A, M>>
+    for RingBuffer<A, N>
+{
     #[inline]
-    #[must_use]
-    fn eq(&self, other: &Self) -> bool {
+    fn eq(&self, other: &RingBuffer<A, M>) -> bool {
         self.len() == other.len() && self.iter().eq(other.iter())
     }
 }
@@ -875,46 +1152,81 @@ where
     N: ChunkLength<A>,
 {
     #[inline]
-    #[must_use]
     fn eq(&self, other: &PrimSlice) -> bool {
         let other = other.borrow();
         self.len() == other.len() && self.iter().eq(other.iter())
     }
 }
 
-impl<A, N> PartialEq<Slice<'_, A, N>> for RingBuffer<A, N>
+impl<A, N, M> PartialEq<Slice<'_, A, M>> for RingBuffer<A, N>
 where
     A: PartialEq,
     N: ChunkLength<A>,
+    M: ChunkLength<A>,
 {
-    fn eq(&self, other: &Slice<'_, A, N>) -> bool {
+    fn eq(&self, other: &Slice<'_, A, M>) -> bool {
         self.len() == other.len() && self.iter().eq(other.iter())
     }
 }
 
-impl<A, N> PartialEq<SliceMut<'_, A, N>> for RingBuffer<A, N>
+impl<A, N, M> PartialEq<SliceMut<'_, A, M>> for RingBuffer<A, N>
 where
     A: PartialEq,
     N: ChunkLength<A>,
+    M: ChunkLength<A>,
 {
-    fn eq(&self, other: &SliceMut<'_, A, N>) -> bool {
+    fn eq(&self, other: &SliceMut<'_, A, M>) -> bool {
         self.len() == other.len() && self.iter().eq(other.iter())
     }
 }
 
 impl<A: Eq, N: ChunkLength<A>> Eq for RingBuffer<A, N> {}
 
-impl<A: PartialOrd, N: ChunkLength<A>> PartialOrd for RingBuffer<A, N> {
+impl<A: PartialOrd, N: ChunkLength<A>, M: ChunkLength<A>> PartialOrd<RingBuffer<A, M>>
+    for RingBuffer<A, N>
+{
     #[inline]
-    #[must_use]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    fn partial_cmp(&self, other: &RingBuffer<A, M>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<A, N, PrimSlice> PartialOrd<PrimSlice> for RingBuffer<A, N>
+where
+    PrimSlice: Borrow<[A]>,
+    A: PartialOrd,
+    N: ChunkLength<A>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &PrimSlice) -> Option<Ordering> {
+        self.iter().partial_cmp(other.borrow().iter())
+    }
+}
+
+impl<A, N, M> PartialOrd<Slice<'_, A, M>> for RingBuffer<A, N>
+where
+    A: PartialOrd,
+    N: ChunkLength<A>,
+    M: ChunkLength<A>,
+{
+    fn partial_cmp(&self, other: &Slice<'_, A, M>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<A, N, M> PartialOrd<SliceMut<'_, A, M>> for RingBuffer<A, N>
+where
+    A: PartialOrd,
+    N: ChunkLength<A>,
+    M: ChunkLength<A>,
+{
+    fn partial_cmp(&self, other: &SliceMut<'_, A, M>) -> Option<Ordering> {
         self.iter().partial_cmp(other.iter())
     }
 }
 
 impl<A: Ord, N: ChunkLength<A>> Ord for RingBuffer<A, N> {
     #[inline]
-    #[must_use]
     fn cmp(&self, other: &Self) -> Ordering {
         self.iter().cmp(other.iter())
     }
@@ -946,11 +1258,19 @@ impl<A: Debug, N: ChunkLength<A>> Debug for RingBuffer<A, N> {
 }
 
 impl<A: Hash, N: ChunkLength<A>> Hash for RingBuffer<A, N> {
+    /// Hash the buffer's contents.
+    ///
+    /// This hashes the length followed by the values, like `[A]` does, but
+    /// when the contents wrap around the end of the buffer, they're passed to
+    /// `A::hash_slice` in two parts. For types like `u8`, which hash a slice
+    /// with a single call to `Hasher::write`, this means two writes instead of
+    /// one, and a hasher which doesn't treat its input as one continuous
+    /// stream may then produce a different result than for the equivalent
+    /// slice. Don't rely on a `RingBuffer` hashing the same as a `[A]`; this
+    /// is why it doesn't implement `Borrow<[A]>`.
     #[inline]
     fn hash<H: Hasher>(&self, hasher: &mut H) {
-        for item in self {
-            item.hash(hasher)
-        }
+        self.as_view_slice().hash(hasher)
     }
 }
 
@@ -986,7 +1306,6 @@ impl<N: ChunkLength<u8>> std::io::Read for RingBuffer<u8, N> {
 }
 
 impl<A, N: ChunkLength<A>> FromIterator<A> for RingBuffer<A, N> {
-    #[must_use]
     fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
         let mut buffer = RingBuffer::new();
         buffer.extend(iter);
@@ -994,12 +1313,103 @@ impl<A, N: ChunkLength<A>> FromIterator<A> for RingBuffer<A, N> {
     }
 }
 
+impl<A, N: ChunkLength<A>> From<Chunk<A, N>> for RingBuffer<A, N> {
+    /// Move the contents of a `Chunk` into a new ring buffer.
+    ///
+    /// Time: O(n) for a bulk copy of the chunk's contents
+    fn from(chunk: Chunk<A, N>) -> Self {
+        let mut buffer = Self::new();
+        unsafe { buffer.copy_from_slice(&chunk, buffer.origin) };
+        buffer.length = chunk.len();
+        mem::forget(chunk);
+        buffer
+    }
+}
+
+impl<A, N: ChunkLength<A>, T> From<InlineArray<A, T>> for RingBuffer<A, N> {
+    /// Move the contents of an `InlineArray` into a new ring buffer.
+    ///
+    /// Panics if the array holds more items than the buffer has room for.
+    #[inline]
+    fn from(mut array: InlineArray<A, T>) -> Self {
+        Self::from(&mut array)
+    }
+}
+
+impl<A, N: ChunkLength<A>, T> From<&mut InlineArray<A, T>> for RingBuffer<A, N> {
+    /// Move the contents of an `InlineArray` into a new ring buffer, leaving
+    /// the array empty.
+    ///
+    /// Panics if the array holds more items than the buffer has room for.
+    fn from(array: &mut InlineArray<A, T>) -> Self {
+        if array.len() > Self::CAPACITY {
+            panic!("RingBuffer::from: chunk size overflow");
+        }
+        let mut buffer = Self::new();
+        unsafe {
+            buffer.copy_from_slice(array, buffer.origin);
+            buffer.length = array.len();
+            *array.len_mut() = 0;
+        }
+        buffer
+    }
+}
+
+impl<A, N: ChunkLength<A>, const K: usize> From<[A; K]> for RingBuffer<A, N> {
+    /// Move the contents of an array into a new ring buffer.
+    ///
+    /// It's a compile time error if `K` is larger than the buffer's capacity.
+    fn from(array: [A; K]) -> Self {
+        const { assert!(K <= N::USIZE, "RingBuffer::from: chunk size overflow") };
+        let array = ManuallyDrop::new(array);
+        let mut buffer = Self::new();
+        unsafe { buffer.copy_from_slice(&*array, buffer.origin) };
+        buffer.length = K;
+        buffer
+    }
+}
+
+impl<A, N: ChunkLength<A>> TryFrom<Vec<A>> for RingBuffer<A, N> {
+    type Error = Vec<A>;
+
+    /// Move the contents of a `Vec` into a new ring buffer.
+    ///
+    /// Fails and gives you the `Vec` back if it's too large to fit in the
+    /// buffer.
+    fn try_from(mut vec: Vec<A>) -> Result<Self, Self::Error> {
+        if vec.len() > Self::CAPACITY {
+            return Err(vec);
+        }
+        let mut buffer = Self::new();
+        unsafe {
+            buffer.copy_from_slice(&vec, buffer.origin);
+            buffer.length = vec.len();
+            vec.set_len(0);
+        }
+        Ok(buffer)
+    }
+}
+
+impl<'a, A: Clone, N: ChunkLength<A>> TryFrom<&'a [A]> for RingBuffer<A, N> {
+    type Error = &'a [A];
+
+    /// Construct a new ring buffer by cloning the contents of a slice.
+    ///
+    /// Fails and gives you the slice back if it's too large to fit in the
+    /// buffer.
+    fn try_from(slice: &'a [A]) -> Result<Self, Self::Error> {
+        if slice.len() > Self::CAPACITY {
+            return Err(slice);
+        }
+        Ok(slice.iter().cloned().collect())
+    }
+}
+
 impl<A, N: ChunkLength<A>> IntoIterator for RingBuffer<A, N> {
     type Item = A;
     type IntoIter = OwnedIter<A, N>;
 
     #[inline]
-    #[must_use]
     fn into_iter(self) -> Self::IntoIter {
         OwnedIter { buffer: self }
     }
@@ -1010,7 +1420,6 @@ impl<'a, A, N: ChunkLength<A>> IntoIterator for &'a RingBuffer<A, N> {
     type IntoIter = Iter<'a, A, N>;
 
     #[inline]
-    #[must_use]
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
@@ -1021,12 +1430,73 @@ impl<'a, A, N: ChunkLength<A>> IntoIterator for &'a mut RingBuffer<A, N> {
     type IntoIter = IterMut<'a, A, N>;
 
     #[inline]
-    #[must_use]
     fn into_iter(self) -> Self::IntoIter {
         self.iter_mut()
     }
 }
 
+impl<A, N> FixedSeq for RingBuffer<A, N>
+where
+    N: ChunkLength<A>,
+{
+    type Item = A;
+    type Iter<'a>
+        = Iter<'a, A, N>
+    where
+        Self: 'a;
+    type Drain<'a>
+        = Drain<'a, A, N>
+    where
+        Self: 'a;
+
+    const CAPACITY: usize = N::USIZE;
+
+    fn len(&self) -> usize {
+        RingBuffer::len(self)
+    }
+
+    fn push_back(&mut self, value: A) {
+        RingBuffer::push_back(self, value)
+    }
+
+    fn pop_back(&mut self) -> Option<A> {
+        RingBuffer::pop_back(self)
+    }
+
+    fn get(&self, index: usize) -> Option<&A> {
+        RingBuffer::get(self, index)
+    }
+
+    fn insert(&mut self, index: usize, value: A) {
+        RingBuffer::insert(self, index, value)
+    }
+
+    fn remove(&mut self, index: usize) -> A {
+        RingBuffer::remove(self, index)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        RingBuffer::iter(self)
+    }
+
+    fn drain(&mut self) -> Self::Drain<'_> {
+        RingBuffer::drain(self)
+    }
+}
+
+impl<A, N> DoubleEndedFixedSeq for RingBuffer<A, N>
+where
+    N: ChunkLength<A>,
+{
+    fn push_front(&mut self, value: A) {
+        RingBuffer::push_front(self, value)
+    }
+
+    fn pop_front(&mut self) -> Option<A> {
+        RingBuffer::pop_front(self)
+    }
+}
+
 // Tests
 
 #[cfg(test)]
@@ -1042,10 +1512,10 @@ mod test {
     fn is_full() {
         let mut chunk = RingBuffer::<_, U64>::new();
         for i in 0..64 {
-            assert_eq!(false, chunk.is_full());
+            assert!(!chunk.is_full());
             chunk.push_back(i);
         }
-        assert_eq!(true, chunk.is_full());
+        assert!(chunk.is_full());
     }
 
     #[test]
@@ -1094,6 +1564,63 @@ mod test {
         assert_eq!(buffer, (0..64).collect::<Vec<u8>>());
     }
 
+    #[test]
+    fn io_write_across_wrap() {
+        use std::io::Write;
+        let mut buffer: RingBuffer<u8, U8> = (0..6).collect();
+        buffer.drop_left(6);
+        assert_eq!(5, buffer.write(&[1, 2, 3, 4, 5]).unwrap());
+        assert_eq!(buffer, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "checked"))]
+    #[should_panic(expected = "RawIndex::from: index 8 out of bounds for capacity 8")]
+    fn raw_index_overflow() {
+        let _: RawIndex<U8> = 8.into();
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "checked"))]
+    #[should_panic(expected = "RingBuffer::ptr: raw index out of bounds")]
+    fn ptr_out_of_bounds() {
+        let buffer: RingBuffer<i32, typenum::U0> = RingBuffer::new();
+        let _ = unsafe { buffer.ptr(0.into()) };
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "checked"))]
+    #[should_panic(expected = "RingBuffer::mut_ptr: raw index out of bounds")]
+    fn mut_ptr_out_of_bounds() {
+        let mut buffer: RingBuffer<i32, typenum::U0> = RingBuffer::new();
+        let _ = unsafe { buffer.mut_ptr(0.into()) };
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "checked"))]
+    #[should_panic(expected = "RingBuffer::copy_from: chunk size overflow")]
+    fn copy_from_overflow() {
+        let mut source: RingBuffer<i32, U8> = (0..8).collect();
+        let mut target: RingBuffer<i32, U8> = RingBuffer::new();
+        unsafe { target.copy_from(&mut source, 0.into(), 0.into(), 9) };
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "checked"))]
+    #[should_panic(expected = "RingBuffer::copy_from_slice: chunk size overflow")]
+    fn copy_from_slice_overflow() {
+        let mut buffer: RingBuffer<i32, U8> = RingBuffer::new();
+        unsafe { buffer.copy_from_slice(&[0; 9], 0.into()) };
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "checked"))]
+    #[should_panic(expected = "RingBuffer::slices: range out of bounds")]
+    fn slices_out_of_bounds() {
+        let buffer: RingBuffer<i32, U8> = (0..4).collect();
+        let _ = buffer.slices(2..5);
+    }
+
     #[test]
     fn io_read() {
         use std::io::Read;
@@ -1125,8 +1652,106 @@ mod test {
         assert_eq!(buffer, expected);
     }
 
-    use crate::tests::DropTest;
+    #[test]
+    fn hash_matches_slice() {
+        use std::collections::hash_map::DefaultHasher;
+        fn hash_of<H: Hash + ?Sized>(value: &H) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+        let mut buffer: RingBuffer<u32> = (0..48).collect();
+        buffer.drop_left(40);
+        buffer.extend(48..80);
+        let vec: Vec<u32> = (40..80).collect();
+        assert_eq!(hash_of(vec.as_slice()), hash_of(&buffer));
+        assert_eq!(hash_of(&vec[10..30]), hash_of(&buffer.slice(10..30)));
+        assert_eq!(hash_of(&vec[10..30]), hash_of(&buffer.slice_mut(10..30)));
+    }
+
+    #[test]
+    fn compare_across_capacities() {
+        let small: RingBuffer<i32, U8> = (0..4).collect();
+        let mut large: RingBuffer<i32> = RingBuffer::with_origin(62);
+        large.extend(0..4);
+        assert_eq!(small, large.slice(..));
+        assert_eq!(large.slice(..), small);
+        assert_eq!(large.slice_mut(..), small.slice(..));
+        assert!(small < large.slice(1..));
+        assert!(large.slice(..3) < small.slice(..));
+        assert!(small.slice(2..) > large.slice_mut(..));
+        assert_eq!(small, large);
+        large.push_back(4);
+        assert!(small < large);
+    }
+
+    #[test]
+    fn conversions() {
+        let mut chunk: Chunk<i32> = Chunk::new();
+        for i in (0..32).rev() {
+            chunk.push_front(i);
+        }
+        let buffer = RingBuffer::from(chunk);
+        assert_eq!(buffer, (0..32).collect::<Vec<_>>());
+        let buffer: RingBuffer<i32> = RingBuffer::from([1, 2, 3]);
+        assert_eq!(buffer, [1, 2, 3]);
+        let buffer = RingBuffer::<i32>::try_from(vec![1, 2, 3]).unwrap();
+        assert_eq!(buffer, [1, 2, 3]);
+        let too_big: Vec<i32> = (0..65).collect();
+        assert_eq!(Err(too_big.clone()), RingBuffer::<i32>::try_from(too_big));
+        let mut array: InlineArray<i32, [usize; 8]> = (0..4).collect();
+        let buffer = RingBuffer::<i32>::from(&mut array);
+        assert_eq!(buffer, [0, 1, 2, 3]);
+        assert!(array.is_empty());
+    }
+
+    use crate::tests::{assert_panics, DropTest, PanicOnClone, TestIter};
+    use std::ops::Bound;
     use std::sync::atomic::{AtomicUsize, Ordering};
+    use typenum::U8;
+
+    #[test]
+    fn type_level_resizing() {
+        use typenum::{U3, U5};
+        let mut buffer: RingBuffer<i32, U5> = RingBuffer::with_origin(3);
+        buffer.extend(0..5);
+        let (left, right) = buffer.split_at_type::<U3>();
+        assert_eq!(left, vec![0, 1, 2]);
+        assert_eq!(right, vec![3, 4]);
+        let buffer: RingBuffer<i32, U5> = right.concat(left);
+        assert_eq!(buffer, vec![3, 4, 0, 1, 2]);
+        let buffer = buffer.resize_capacity::<U3>().unwrap_err();
+        let buffer = buffer.resize_capacity::<U8>().unwrap();
+        assert_eq!(buffer, vec![3, 4, 0, 1, 2]);
+    }
+
+    #[test]
+    fn panic_safety() {
+        let mut buffer: RingBuffer<i32, U8> = RingBuffer::with_origin(6);
+        buffer.extend(0..4);
+        assert_panics(|| buffer.insert_from(1, TestIter::panic_after(10..13, 3, 2)));
+        assert_eq!(buffer, vec![0, 10, 11, 1, 2, 3]);
+        buffer.insert_from(4, TestIter::stop_after(20..22, 2, 1));
+        assert_eq!(buffer, vec![0, 10, 11, 1, 20, 2, 3]);
+
+        let counter = AtomicUsize::new(0);
+        {
+            let mut buffer: RingBuffer<PanicOnClone<'_>, U8> = RingBuffer::with_origin(7);
+            buffer.push_back(PanicOnClone::new(&counter));
+            buffer.push_back(PanicOnClone::panicking(&counter));
+            assert_panics(|| drop(buffer.clone()));
+            assert_eq!(2, counter.load(Ordering::Relaxed));
+
+            buffer.push_back(PanicOnClone::new(&counter));
+            let values: Vec<_> = (0..3).map(|_| PanicOnClone::new(&counter)).collect();
+            assert_panics(|| {
+                buffer.insert_from(2, TestIter::panic_after(values.into_iter(), 3, 2))
+            });
+            assert_eq!(5, buffer.len());
+            assert_eq!(5, counter.load(Ordering::Relaxed));
+        }
+        assert_eq!(0, counter.load(Ordering::Relaxed));
+    }
 
     #[test]
     fn dropping() {
@@ -1147,4 +1772,213 @@ mod test {
         }
         assert_eq!(0, counter.load(Ordering::Relaxed));
     }
+
+    #[test]
+    fn rebalance_siblings() {
+        let mut left: RingBuffer<i32, U8> = (0..8).collect();
+        let mut right: RingBuffer<i32, U8> = RingBuffer::unit(8);
+        left.balance_with(&mut right);
+        assert_eq!((5, 4), (left.len(), right.len()));
+        assert!(!left.merge_if_fits(&mut right));
+        let mut siblings = [left, right, RingBuffer::new()];
+        RingBuffer::steal_from_right(&mut siblings, 0, 1);
+        RingBuffer::steal_from_left(&mut siblings, 2, 2);
+        RingBuffer::steal_from_left(&mut siblings, 1, 3);
+        let lens: Vec<_> = siblings.iter().map(|chunk| chunk.len()).collect();
+        assert_eq!(vec![3, 4, 2], lens);
+        let [mut left, mut middle, mut right] = siblings;
+        assert!(middle.merge_if_fits(&mut right));
+        assert!(right.is_empty());
+        left.balance_with(&mut middle);
+        assert_eq!((4, 5), (left.len(), middle.len()));
+        let all: Vec<_> = left.iter().chain(middle.iter()).cloned().collect();
+        assert_eq!((0..9).collect::<Vec<_>>(), all);
+    }
+
+    #[test]
+    fn cursor_peeking() {
+        let mut chunk: RingBuffer<i32, U8> = (1..4).collect();
+        let mut cursor = chunk.cursor();
+        assert_eq!((None, Some(&2)), (cursor.peek_prev(), cursor.peek_next()));
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!((Some(&2), None), (cursor.peek_prev(), cursor.peek_next()));
+        cursor.move_next();
+        assert_eq!(
+            (Some(&3), Some(&1)),
+            (cursor.peek_prev(), cursor.peek_next())
+        );
+        let mut cursor = chunk.cursor_mut();
+        assert_eq!(None, cursor.peek_prev());
+        assert_eq!(Some(&mut 2), cursor.peek_next());
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(Some(&mut 2), cursor.peek_prev());
+        assert_eq!(None, cursor.peek_next());
+        cursor.move_next();
+        assert_eq!(Some(&mut 3), cursor.peek_prev());
+        assert_eq!(Some(&mut 1), cursor.peek_next());
+    }
+
+    #[test]
+    fn cursor_editing() {
+        let mut chunk: RingBuffer<i32, U8> = (1..5).collect();
+        {
+            let mut cursor = chunk.cursor_mut();
+            cursor.move_next();
+            assert_eq!(Some(&mut 2), cursor.current());
+            cursor.insert_before(10);
+            cursor.insert_after(20);
+            assert_eq!(Some(2), cursor.index());
+            assert_eq!(Some(2), cursor.remove_current());
+            assert_eq!(Some(&mut 20), cursor.current());
+            cursor.move_prev();
+            cursor.move_prev();
+            cursor.move_prev();
+            assert_eq!(None, cursor.index());
+            assert_eq!(Some(&mut 4), cursor.peek_prev());
+            cursor.insert_after(0);
+            cursor.insert_before(5);
+            assert_eq!(None, cursor.current());
+        }
+        let expected: Vec<i32> = vec![0, 1, 10, 20, 3, 4, 5];
+        assert_eq!(expected, chunk.iter().cloned().collect::<Vec<_>>());
+        let mut cursor = chunk.cursor();
+        let mut backwards = Vec::new();
+        cursor.move_prev();
+        assert_eq!(None, cursor.current());
+        cursor.move_prev();
+        while let Some(value) = cursor.current() {
+            backwards.push(*value);
+            cursor.move_prev();
+        }
+        assert_eq!(
+            expected.iter().rev().cloned().collect::<Vec<_>>(),
+            backwards
+        );
+        let mut cursor = chunk.cursor_mut();
+        for _ in 0..3 {
+            cursor.move_next();
+        }
+        let rest = cursor.split_at_cursor();
+        assert_eq!(None, cursor.current());
+        assert_eq!(vec![20, 3, 4, 5], rest.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(3, chunk.len());
+    }
+
+    #[test]
+    fn cursor_across_wrap() {
+        let mut buffer: RingBuffer<i32, U8> = RingBuffer::new();
+        for i in 0..3 {
+            buffer.push_back(i);
+            buffer.push_front(-i - 1);
+        }
+        let mut cursor = buffer.cursor();
+        let mut forwards = Vec::new();
+        while let Some(value) = cursor.current() {
+            forwards.push(*value);
+            cursor.move_next();
+        }
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), forwards);
+        let mut cursor = buffer.cursor_mut();
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.insert_after(7);
+        assert_eq!(Some(&mut 2), cursor.current());
+        assert_eq!(Some(&mut 7), cursor.peek_next());
+        assert_eq!(Some(2), cursor.remove_current());
+        assert_eq!(Some(&mut 7), cursor.current());
+    }
+
+    #[test]
+    fn cursor_editing_everywhere() {
+        for origin in 0..8 {
+            for len in 0..8 {
+                for position in 0..=len {
+                    for edit in 0..3 {
+                        let mut buffer: RingBuffer<usize, U8> = RingBuffer::new();
+                        for _ in 0..origin {
+                            buffer.push_back(0);
+                            buffer.pop_front();
+                        }
+                        buffer.extend(0..len);
+                        let mut model: Vec<usize> = (0..len).collect();
+                        let mut cursor = buffer.cursor_mut();
+                        for _ in 0..position {
+                            cursor.move_next();
+                        }
+                        let mut index = position;
+                        match edit {
+                            0 => {
+                                cursor.insert_before(100);
+                                model.insert(index, 100);
+                                index += 1;
+                            }
+                            1 if index == len => {
+                                cursor.insert_after(100);
+                                model.insert(0, 100);
+                                index += 1;
+                            }
+                            1 => {
+                                cursor.insert_after(100);
+                                model.insert(index + 1, 100);
+                            }
+                            _ => {
+                                let removed = cursor.remove_current();
+                                assert_eq!(model.get(index).cloned(), removed);
+                                if index < len {
+                                    model.remove(index);
+                                }
+                            }
+                        }
+                        let prev = index.checked_sub(1).and_then(|i| model.get(i));
+                        let next = if index == model.len() {
+                            model.first()
+                        } else {
+                            model.get(index + 1)
+                        };
+                        assert_eq!(model.get(index), cursor.current().map(|v| &*v));
+                        assert_eq!(prev, cursor.peek_prev().map(|v| &*v));
+                        assert_eq!(next, cursor.peek_next().map(|v| &*v));
+                        assert_eq!(buffer, model);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn slices_across_wrap() {
+        let mut buffer: RingBuffer<i32, U8> = RingBuffer::new();
+        for i in 0..4 {
+            buffer.push_back(i);
+            buffer.push_front(-i - 1);
+        }
+        let (left, right) = buffer.slice_mut(2..6).split_at(2);
+        assert_eq!(left, [-2, -1]);
+        assert_eq!(right, [0, 1]);
+        let mut slice = buffer.slice_mut((Bound::Excluded(0), Bound::Unbounded));
+        assert_eq!(-3, slice.set(0, 10));
+        slice.iter_mut().for_each(|value| *value *= 2);
+        assert_eq!(7, slice.iter().len());
+        assert_eq!(buffer.slice(..2), [-4, 20]);
+        assert_eq!(Some(&6), buffer.slice(5..).last());
+    }
+
+    #[test]
+    fn boxed_buffers() {
+        let mut slot = MaybeUninit::uninit();
+        let buffer = RingBuffer::<i32, U8>::init_in_place(&mut slot);
+        buffer.push_front(1);
+        assert_eq!(1, buffer.len());
+        // A 128k buffer on a 64k stack would overflow if it ever touched it.
+        crate::tests::on_small_stack(|| {
+            type Buffer = RingBuffer<u64, typenum::U16384>;
+            let mut buffer = Buffer::new_boxed();
+            buffer.push_back(1);
+            buffer.push_front(0);
+            assert_eq!(*buffer, *buffer.clone_boxed());
+            assert!(Buffer::collect_boxed(0..16384).is_full());
+        });
+    }
 }
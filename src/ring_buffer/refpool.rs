@@ -11,11 +11,7 @@ where
     N: ChunkLength<A>,
 {
     unsafe fn default_uninit(target: &mut MaybeUninit<Self>) {
-        let ptr = target.as_mut_ptr();
-        let origin_ptr: *mut RawIndex<N> = &mut (*ptr).origin;
-        let length_ptr: *mut usize = &mut (*ptr).length;
-        origin_ptr.write(0.into());
-        length_ptr.write(0);
+        RingBuffer::init_in_place(target);
     }
 }
 
@@ -9,43 +9,55 @@ use std::fmt::Error;
 use std::fmt::Formatter;
 use std::hash::Hash;
 use std::hash::Hasher;
+use std::marker::PhantomData;
+use std::mem;
 use std::ops::IndexMut;
-use std::ops::{Bound, Index, Range, RangeBounds};
+use std::ops::{Index, RangeBounds};
 
 use crate::types::ChunkLength;
 
-use super::{Iter, IterMut, RingBuffer};
+use super::view::parse_range;
+use super::{Iter, IterMut, RingBuffer, ViewSlice, ViewSliceMut};
 
 /// An indexable representation of a subset of a `RingBuffer`.
+///
+/// This is a [`ViewSlice`][ViewSlice] which remembers the capacity of the
+/// buffer it came from, so it can be copied back into one.
+///
+/// [ViewSlice]: struct.ViewSlice.html
 pub struct Slice<'a, A, N: ChunkLength<A>> {
-    pub(crate) buffer: &'a RingBuffer<A, N>,
-    pub(crate) range: Range<usize>,
+    slice: ViewSlice<'a, A>,
+    capacity: PhantomData<N>,
 }
 
 impl<'a, A: 'a, N: ChunkLength<A> + 'a> Slice<'a, A, N> {
+    #[inline]
+    pub(crate) fn new(slice: ViewSlice<'a, A>) -> Self {
+        Slice {
+            slice,
+            capacity: PhantomData,
+        }
+    }
+
     /// Get the length of the slice.
     #[inline]
     #[must_use]
     pub fn len(&self) -> usize {
-        self.range.end - self.range.start
+        self.slice.len()
     }
 
     /// Test if the slice is empty.
     #[inline]
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.len() == 0
+        self.slice.is_empty()
     }
 
     /// Get a reference to the value at a given index.
     #[inline]
     #[must_use]
     pub fn get(&self, index: usize) -> Option<&A> {
-        if index >= self.len() {
-            None
-        } else {
-            Some(unsafe { self.get_unchecked(index) })
-        }
+        self.slice.get(index)
     }
 
     /// Get an unchecked reference to the value at the given index.
@@ -55,37 +67,33 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> Slice<'a, A, N> {
     /// You must ensure the index is not out of bounds.
     #[must_use]
     pub unsafe fn get_unchecked(&self, index: usize) -> &A {
-        self.buffer.get_unchecked(self.range.start + index)
+        let (left, right) = self.slice.as_slices();
+        if index < left.len() {
+            left.get_unchecked(index)
+        } else {
+            right.get_unchecked(index - left.len())
+        }
     }
 
     /// Get a reference to the first value in the slice.
     #[inline]
     #[must_use]
     pub fn first(&self) -> Option<&A> {
-        self.get(0)
+        self.slice.first()
     }
 
     /// Get a reference to the last value in the slice.
     #[inline]
     #[must_use]
     pub fn last(&self) -> Option<&A> {
-        if self.is_empty() {
-            None
-        } else {
-            self.get(self.len() - 1)
-        }
+        self.slice.last()
     }
 
     /// Get an iterator over references to the items in the slice in order.
     #[inline]
     #[must_use]
     pub fn iter(&self) -> Iter<'_, A, N> {
-        Iter {
-            buffer: self.buffer,
-            left_index: self.buffer.origin + self.range.start,
-            right_index: self.buffer.origin + self.range.start + self.len(),
-            remaining: self.len(),
-        }
+        Iter::new(self.slice.iter())
     }
 
     /// Create a subslice of this slice.
@@ -94,28 +102,8 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> Slice<'a, A, N> {
     /// clone it first: `my_slice.clone().slice(1..2)`.
     #[must_use]
     pub fn slice<R: RangeBounds<usize>>(self, range: R) -> Slice<'a, A, N> {
-        let new_range = Range {
-            start: match range.start_bound() {
-                Bound::Unbounded => self.range.start,
-                Bound::Included(index) => self.range.start + index,
-                Bound::Excluded(_) => unimplemented!(),
-            },
-            end: match range.end_bound() {
-                Bound::Unbounded => self.range.end,
-                Bound::Included(index) => self.range.start + index + 1,
-                Bound::Excluded(index) => self.range.start + index,
-            },
-        };
-        if new_range.start < self.range.start
-            || new_range.end > self.range.end
-            || new_range.start > new_range.end
-        {
-            panic!("Slice::slice: index out of bounds");
-        }
-        Slice {
-            buffer: self.buffer,
-            range: new_range,
-        }
+        let range = parse_range(range, self.len(), "Slice::slice");
+        Slice::new(self.slice.slice(range))
     }
 
     /// Split the slice into two subslices at the given index.
@@ -124,23 +112,8 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> Slice<'a, A, N> {
         if index > self.len() {
             panic!("Slice::split_at: index out of bounds");
         }
-        let index = self.range.start + index;
-        (
-            Slice {
-                buffer: self.buffer,
-                range: Range {
-                    start: self.range.start,
-                    end: index,
-                },
-            },
-            Slice {
-                buffer: self.buffer,
-                range: Range {
-                    start: index,
-                    end: self.range.end,
-                },
-            },
-        )
+        let (left, right) = self.slice.split_at(index);
+        (Slice::new(left), Slice::new(right))
     }
 
     /// Construct a new `RingBuffer` by copying the elements in this slice.
@@ -205,26 +178,15 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> Slice<'a, A, N> {
 
 impl<'a, A: 'a, N: ChunkLength<A> + 'a> From<&'a RingBuffer<A, N>> for Slice<'a, A, N> {
     #[inline]
-    #[must_use]
     fn from(buffer: &'a RingBuffer<A, N>) -> Self {
-        Slice {
-            range: Range {
-                start: 0,
-                end: buffer.len(),
-            },
-            buffer,
-        }
+        Slice::new(buffer.as_view_slice())
     }
 }
 
 impl<'a, A: 'a, N: ChunkLength<A> + 'a> Clone for Slice<'a, A, N> {
     #[inline]
-    #[must_use]
     fn clone(&self) -> Self {
-        Slice {
-            buffer: self.buffer,
-            range: self.range.clone(),
-        }
+        Slice::new(self.slice)
     }
 }
 
@@ -232,36 +194,34 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> Index<usize> for Slice<'a, A, N> {
     type Output = A;
 
     #[inline]
-    #[must_use]
     fn index(&self, index: usize) -> &Self::Output {
-        self.buffer.index(self.range.start + index)
+        &self.slice[index]
     }
 }
 
-impl<'a, A: PartialEq + 'a, N: ChunkLength<A> + 'a> PartialEq for Slice<'a, A, N> {
+impl<'a, A: PartialEq + 'a, N: ChunkLength<A> + 'a, M: ChunkLength<A>> PartialEq<Slice<'_, A, M>>
+    for Slice<'a, A, N>
+{
     #[inline]
-    #[must_use]
-    fn eq(&self, other: &Self) -> bool {
+    fn eq(&self, other: &Slice<'_, A, M>) -> bool {
         self.len() == other.len() && self.iter().eq(other.iter())
     }
 }
 
-impl<'a, A: PartialEq + 'a, N: ChunkLength<A> + 'a> PartialEq<SliceMut<'a, A, N>>
+impl<'a, A: PartialEq + 'a, N: ChunkLength<A> + 'a, M: ChunkLength<A>> PartialEq<SliceMut<'_, A, M>>
     for Slice<'a, A, N>
 {
     #[inline]
-    #[must_use]
-    fn eq(&self, other: &SliceMut<'a, A, N>) -> bool {
+    fn eq(&self, other: &SliceMut<'_, A, M>) -> bool {
         self.len() == other.len() && self.iter().eq(other.iter())
     }
 }
 
-impl<'a, A: PartialEq + 'a, N: ChunkLength<A> + 'a> PartialEq<RingBuffer<A, N>>
+impl<'a, A: PartialEq + 'a, N: ChunkLength<A> + 'a, M: ChunkLength<A>> PartialEq<RingBuffer<A, M>>
     for Slice<'a, A, N>
 {
     #[inline]
-    #[must_use]
-    fn eq(&self, other: &RingBuffer<A, N>) -> bool {
+    fn eq(&self, other: &RingBuffer<A, M>) -> bool {
         self.len() == other.len() && self.iter().eq(other.iter())
     }
 }
@@ -271,7 +231,6 @@ where
     S: Borrow<[A]>,
 {
     #[inline]
-    #[must_use]
     fn eq(&self, other: &S) -> bool {
         let other = other.borrow();
         self.len() == other.len() && self.iter().eq(other.iter())
@@ -280,17 +239,45 @@ where
 
 impl<'a, A: Eq + 'a, N: ChunkLength<A> + 'a> Eq for Slice<'a, A, N> {}
 
-impl<'a, A: PartialOrd + 'a, N: ChunkLength<A> + 'a> PartialOrd for Slice<'a, A, N> {
+impl<'a, A: PartialOrd + 'a, N: ChunkLength<A> + 'a, M: ChunkLength<A>> PartialOrd<Slice<'_, A, M>>
+    for Slice<'a, A, N>
+{
     #[inline]
-    #[must_use]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    fn partial_cmp(&self, other: &Slice<'_, A, M>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<'a, A: PartialOrd + 'a, N: ChunkLength<A> + 'a, M: ChunkLength<A>>
+    PartialOrd<SliceMut<'_, A, M>> for Slice<'a, A, N>
+{
+    #[inline]
+    fn partial_cmp(&self, other: &SliceMut<'_, A, M>) -> Option<Ordering> {
         self.iter().partial_cmp(other.iter())
     }
 }
 
+impl<'a, A: PartialOrd + 'a, N: ChunkLength<A> + 'a, M: ChunkLength<A>> PartialOrd<RingBuffer<A, M>>
+    for Slice<'a, A, N>
+{
+    #[inline]
+    fn partial_cmp(&self, other: &RingBuffer<A, M>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<'a, A: PartialOrd + 'a, N: ChunkLength<A> + 'a, S> PartialOrd<S> for Slice<'a, A, N>
+where
+    S: Borrow<[A]>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &S) -> Option<Ordering> {
+        self.iter().partial_cmp(other.borrow().iter())
+    }
+}
+
 impl<'a, A: Ord + 'a, N: ChunkLength<A> + 'a> Ord for Slice<'a, A, N> {
     #[inline]
-    #[must_use]
     fn cmp(&self, other: &Self) -> Ordering {
         self.iter().cmp(other.iter())
     }
@@ -304,11 +291,16 @@ impl<'a, A: Debug + 'a, N: ChunkLength<A> + 'a> Debug for Slice<'a, A, N> {
 }
 
 impl<'a, A: Hash + 'a, N: ChunkLength<A> + 'a> Hash for Slice<'a, A, N> {
+    /// Hash the slice's contents.
+    ///
+    /// As with [`RingBuffer`][RingBuffer]'s `Hash` implementation, values
+    /// wrapping around the end of the buffer are hashed in two parts, so
+    /// don't rely on this hashing the same as the equivalent `[A]` slice.
+    ///
+    /// [RingBuffer]: struct.RingBuffer.html
     #[inline]
     fn hash<H: Hasher>(&self, hasher: &mut H) {
-        for item in self {
-            item.hash(hasher)
-        }
+        self.slice.hash(hasher)
     }
 }
 
@@ -317,7 +309,6 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> IntoIterator for &'a Slice<'a, A, N> {
     type IntoIter = Iter<'a, A, N>;
 
     #[inline]
-    #[must_use]
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
@@ -326,45 +317,51 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> IntoIterator for &'a Slice<'a, A, N> {
 // Mutable slice
 
 /// An indexable representation of a mutable subset of a `RingBuffer`.
+///
+/// This is a [`ViewSliceMut`][ViewSliceMut] which remembers the capacity of
+/// the buffer it came from, so it can be copied back into one.
+///
+/// [ViewSliceMut]: struct.ViewSliceMut.html
 pub struct SliceMut<'a, A, N: ChunkLength<A>> {
-    pub(crate) buffer: &'a mut RingBuffer<A, N>,
-    pub(crate) range: Range<usize>,
+    slice: ViewSliceMut<'a, A>,
+    capacity: PhantomData<N>,
 }
 
 impl<'a, A: 'a, N: ChunkLength<A> + 'a> SliceMut<'a, A, N> {
+    #[inline]
+    pub(crate) fn new(slice: ViewSliceMut<'a, A>) -> Self {
+        SliceMut {
+            slice,
+            capacity: PhantomData,
+        }
+    }
+
     /// Downgrade this slice into a non-mutable slice.
     #[inline]
     #[must_use]
     pub fn unmut(self) -> Slice<'a, A, N> {
-        Slice {
-            buffer: self.buffer,
-            range: self.range,
-        }
+        Slice::new(self.slice.unmut())
     }
 
     /// Get the length of the slice.
     #[inline]
     #[must_use]
     pub fn len(&self) -> usize {
-        self.range.end - self.range.start
+        self.slice.len()
     }
 
     /// Test if the slice is empty.
     #[inline]
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.len() == 0
+        self.slice.is_empty()
     }
 
     /// Get a reference to the value at a given index.
     #[inline]
     #[must_use]
     pub fn get(&self, index: usize) -> Option<&A> {
-        if index >= self.len() {
-            None
-        } else {
-            Some(unsafe { self.get_unchecked(index) })
-        }
+        self.slice.get(index)
     }
 
     /// Get an unchecked reference to the value at the given index.
@@ -374,18 +371,19 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> SliceMut<'a, A, N> {
     /// You must ensure the index is not out of bounds.
     #[must_use]
     pub unsafe fn get_unchecked(&self, index: usize) -> &A {
-        self.buffer.get_unchecked(self.range.start + index)
+        let (left, right) = self.slice.as_slices();
+        if index < left.len() {
+            left.get_unchecked(index)
+        } else {
+            right.get_unchecked(index - left.len())
+        }
     }
 
     /// Get a mutable reference to the value at a given index.
     #[inline]
     #[must_use]
     pub fn get_mut(&mut self, index: usize) -> Option<&mut A> {
-        if index >= self.len() {
-            None
-        } else {
-            Some(unsafe { self.get_unchecked_mut(index) })
-        }
+        self.slice.get_mut(index)
     }
 
     /// Get an unchecked mutable reference to the value at the given index.
@@ -395,14 +393,19 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> SliceMut<'a, A, N> {
     /// You must ensure the index is not out of bounds.
     #[must_use]
     pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut A {
-        self.buffer.get_unchecked_mut(self.range.start + index)
+        let (left, right) = self.slice.as_mut_slices();
+        if index < left.len() {
+            left.get_unchecked_mut(index)
+        } else {
+            right.get_unchecked_mut(index - left.len())
+        }
     }
 
     /// Get a reference to the first value in the slice.
     #[inline]
     #[must_use]
     pub fn first(&self) -> Option<&A> {
-        self.get(0)
+        self.slice.first()
     }
 
     /// Get a mutable reference to the first value in the slice.
@@ -416,11 +419,7 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> SliceMut<'a, A, N> {
     #[inline]
     #[must_use]
     pub fn last(&self) -> Option<&A> {
-        if self.is_empty() {
-            None
-        } else {
-            self.get(self.len() - 1)
-        }
+        self.slice.last()
     }
 
     /// Get a mutable reference to the last value in the slice.
@@ -438,12 +437,7 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> SliceMut<'a, A, N> {
     #[inline]
     #[must_use]
     pub fn iter(&self) -> Iter<'_, A, N> {
-        Iter {
-            buffer: self.buffer,
-            left_index: self.buffer.origin + self.range.start,
-            right_index: self.buffer.origin + self.range.start + self.len(),
-            remaining: self.len(),
-        }
+        Iter::new(self.slice.iter())
     }
 
     /// Get an iterator over mutable references to the items in the slice in
@@ -451,11 +445,7 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> SliceMut<'a, A, N> {
     #[inline]
     #[must_use]
     pub fn iter_mut(&mut self) -> IterMut<'_, A, N> {
-        IterMut::new_slice(
-            self.buffer,
-            self.buffer.origin + self.range.start,
-            self.len(),
-        )
+        IterMut::new(self.slice.iter_mut())
     }
 
     /// Create a subslice of this slice.
@@ -465,28 +455,8 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> SliceMut<'a, A, N> {
     /// `RingBuffer` at any one time, so that's just how it's got to be.
     #[must_use]
     pub fn slice<R: RangeBounds<usize>>(self, range: R) -> SliceMut<'a, A, N> {
-        let new_range = Range {
-            start: match range.start_bound() {
-                Bound::Unbounded => self.range.start,
-                Bound::Included(index) => self.range.start + index,
-                Bound::Excluded(_) => unimplemented!(),
-            },
-            end: match range.end_bound() {
-                Bound::Unbounded => self.range.end,
-                Bound::Included(index) => self.range.start + index + 1,
-                Bound::Excluded(index) => self.range.start + index,
-            },
-        };
-        if new_range.start < self.range.start
-            || new_range.end > self.range.end
-            || new_range.start > new_range.end
-        {
-            panic!("Slice::slice: index out of bounds");
-        }
-        SliceMut {
-            buffer: self.buffer,
-            range: new_range,
-        }
+        let range = parse_range(range, self.len(), "SliceMut::slice");
+        SliceMut::new(self.slice.slice(range))
     }
 
     /// Split the slice into two subslices at the given index.
@@ -495,24 +465,8 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> SliceMut<'a, A, N> {
         if index > self.len() {
             panic!("SliceMut::split_at: index out of bounds");
         }
-        let index = self.range.start + index;
-        let ptr: *mut RingBuffer<A, N> = self.buffer;
-        (
-            SliceMut {
-                buffer: unsafe { &mut *ptr },
-                range: Range {
-                    start: self.range.start,
-                    end: index,
-                },
-            },
-            SliceMut {
-                buffer: unsafe { &mut *ptr },
-                range: Range {
-                    start: index,
-                    end: self.range.end,
-                },
-            },
-        )
+        let (left, right) = self.slice.split_at(index);
+        (SliceMut::new(left), SliceMut::new(right))
     }
 
     /// Update the value at index `index`, returning the old value.
@@ -521,10 +475,9 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> SliceMut<'a, A, N> {
     #[inline]
     #[must_use]
     pub fn set(&mut self, index: usize, value: A) -> A {
-        if index >= self.len() {
-            panic!("SliceMut::set: index out of bounds");
-        } else {
-            self.buffer.set(self.range.start + index, value)
+        match self.get_mut(index) {
+            Some(target) => mem::replace(target, value),
+            None => panic!("SliceMut::set: index out of bounds"),
         }
     }
 
@@ -589,23 +542,15 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> SliceMut<'a, A, N> {
 }
 
 impl<'a, A: 'a, N: ChunkLength<A> + 'a> From<&'a mut RingBuffer<A, N>> for SliceMut<'a, A, N> {
-    #[must_use]
     fn from(buffer: &'a mut RingBuffer<A, N>) -> Self {
-        SliceMut {
-            range: Range {
-                start: 0,
-                end: buffer.len(),
-            },
-            buffer,
-        }
+        SliceMut::new(buffer.as_view_slice_mut())
     }
 }
 
-impl<'a, A: 'a, N: ChunkLength<A> + 'a> Into<Slice<'a, A, N>> for SliceMut<'a, A, N> {
+impl<'a, A: 'a, N: ChunkLength<A> + 'a> From<SliceMut<'a, A, N>> for Slice<'a, A, N> {
     #[inline]
-    #[must_use]
-    fn into(self) -> Slice<'a, A, N> {
-        self.unmut()
+    fn from(slice: SliceMut<'a, A, N>) -> Self {
+        slice.unmut()
     }
 }
 
@@ -613,44 +558,41 @@ impl<'a, A: 'a, N: ChunkLength<A> + 'a> Index<usize> for SliceMut<'a, A, N> {
     type Output = A;
 
     #[inline]
-    #[must_use]
     fn index(&self, index: usize) -> &Self::Output {
-        self.buffer.index(self.range.start + index)
+        &self.slice[index]
     }
 }
 
 impl<'a, A: 'a, N: ChunkLength<A> + 'a> IndexMut<usize> for SliceMut<'a, A, N> {
     #[inline]
-    #[must_use]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        self.buffer.index_mut(self.range.start + index)
+        &mut self.slice[index]
     }
 }
 
-impl<'a, A: PartialEq + 'a, N: ChunkLength<A> + 'a> PartialEq for SliceMut<'a, A, N> {
+impl<'a, A: PartialEq + 'a, N: ChunkLength<A> + 'a, M: ChunkLength<A>> PartialEq<SliceMut<'_, A, M>>
+    for SliceMut<'a, A, N>
+{
     #[inline]
-    #[must_use]
-    fn eq(&self, other: &Self) -> bool {
+    fn eq(&self, other: &SliceMut<'_, A, M>) -> bool {
         self.len() == other.len() && self.iter().eq(other.iter())
     }
 }
 
-impl<'a, A: PartialEq + 'a, N: ChunkLength<A> + 'a> PartialEq<Slice<'a, A, N>>
+impl<'a, A: PartialEq + 'a, N: ChunkLength<A> + 'a, M: ChunkLength<A>> PartialEq<Slice<'_, A, M>>
     for SliceMut<'a, A, N>
 {
     #[inline]
-    #[must_use]
-    fn eq(&self, other: &Slice<'a, A, N>) -> bool {
+    fn eq(&self, other: &Slice<'_, A, M>) -> bool {
         self.len() == other.len() && self.iter().eq(other.iter())
     }
 }
 
-impl<'a, A: PartialEq + 'a, N: ChunkLength<A> + 'a> PartialEq<RingBuffer<A, N>>
+impl<'a, A: PartialEq + 'a, N: ChunkLength<A> + 'a, M: ChunkLength<A>> PartialEq<RingBuffer<A, M>>
     for SliceMut<'a, A, N>
 {
     #[inline]
-    #[must_use]
-    fn eq(&self, other: &RingBuffer<A, N>) -> bool {
+    fn eq(&self, other: &RingBuffer<A, M>) -> bool {
         self.len() == other.len() && self.iter().eq(other.iter())
     }
 }
@@ -660,7 +602,6 @@ where
     S: Borrow<[A]>,
 {
     #[inline]
-    #[must_use]
     fn eq(&self, other: &S) -> bool {
         let other = other.borrow();
         self.len() == other.len() && self.iter().eq(other.iter())
@@ -669,17 +610,45 @@ where
 
 impl<'a, A: Eq + 'a, N: ChunkLength<A> + 'a> Eq for SliceMut<'a, A, N> {}
 
-impl<'a, A: PartialOrd + 'a, N: ChunkLength<A> + 'a> PartialOrd for SliceMut<'a, A, N> {
+impl<'a, A: PartialOrd + 'a, N: ChunkLength<A> + 'a, M: ChunkLength<A>>
+    PartialOrd<SliceMut<'_, A, M>> for SliceMut<'a, A, N>
+{
     #[inline]
-    #[must_use]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    fn partial_cmp(&self, other: &SliceMut<'_, A, M>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<'a, A: PartialOrd + 'a, N: ChunkLength<A> + 'a, M: ChunkLength<A>> PartialOrd<Slice<'_, A, M>>
+    for SliceMut<'a, A, N>
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Slice<'_, A, M>) -> Option<Ordering> {
         self.iter().partial_cmp(other.iter())
     }
 }
 
+impl<'a, A: PartialOrd + 'a, N: ChunkLength<A> + 'a, M: ChunkLength<A>> PartialOrd<RingBuffer<A, M>>
+    for SliceMut<'a, A, N>
+{
+    #[inline]
+    fn partial_cmp(&self, other: &RingBuffer<A, M>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<'a, A: PartialOrd + 'a, N: ChunkLength<A> + 'a, S> PartialOrd<S> for SliceMut<'a, A, N>
+where
+    S: Borrow<[A]>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &S) -> Option<Ordering> {
+        self.iter().partial_cmp(other.borrow().iter())
+    }
+}
+
 impl<'a, A: Ord + 'a, N: ChunkLength<A> + 'a> Ord for SliceMut<'a, A, N> {
     #[inline]
-    #[must_use]
     fn cmp(&self, other: &Self) -> Ordering {
         self.iter().cmp(other.iter())
     }
@@ -693,31 +662,34 @@ impl<'a, A: Debug + 'a, N: ChunkLength<A> + 'a> Debug for SliceMut<'a, A, N> {
 }
 
 impl<'a, A: Hash + 'a, N: ChunkLength<A> + 'a> Hash for SliceMut<'a, A, N> {
+    /// Hash the slice's contents.
+    ///
+    /// As with [`RingBuffer`][RingBuffer]'s `Hash` implementation, values
+    /// wrapping around the end of the buffer are hashed in two parts, so
+    /// don't rely on this hashing the same as the equivalent `[A]` slice.
+    ///
+    /// [RingBuffer]: struct.RingBuffer.html
     #[inline]
     fn hash<H: Hasher>(&self, hasher: &mut H) {
-        for item in self {
-            item.hash(hasher)
-        }
+        self.slice.hash(hasher)
     }
 }
 
-impl<'a, 'b, A: 'a, N: ChunkLength<A> + 'a> IntoIterator for &'a SliceMut<'a, A, N> {
+impl<'a, A: 'a, N: ChunkLength<A> + 'a> IntoIterator for &'a SliceMut<'a, A, N> {
     type Item = &'a A;
     type IntoIter = Iter<'a, A, N>;
 
     #[inline]
-    #[must_use]
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
-impl<'a, 'b, A: 'a, N: ChunkLength<A> + 'a> IntoIterator for &'a mut SliceMut<'a, A, N> {
+impl<'a, A: 'a, N: ChunkLength<A> + 'a> IntoIterator for &'a mut SliceMut<'a, A, N> {
     type Item = &'a mut A;
     type IntoIter = IterMut<'a, A, N>;
 
     #[inline]
-    #[must_use]
     fn into_iter(self) -> Self::IntoIter {
         self.iter_mut()
     }
@@ -0,0 +1,1177 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::fmt::{Debug, Error, Formatter};
+use std::hash::{Hash, Hasher};
+use std::iter::Chain;
+use std::mem::MaybeUninit;
+use std::ops::{Bound, Index, IndexMut, Range, RangeBounds};
+use std::ptr;
+use std::slice::{from_raw_parts, from_raw_parts_mut, Iter as SliceIter, IterMut as SliceIterMut};
+
+/// An iterator over references to the values in a `RingBufferView`.
+pub type ViewIter<'a, A> = Chain<SliceIter<'a, A>, SliceIter<'a, A>>;
+
+/// An iterator over mutable references to the values in a `RingBufferView`.
+pub type ViewIterMut<'a, A> = Chain<SliceIterMut<'a, A>, SliceIterMut<'a, A>>;
+
+/// The bookkeeping for a [`RingBufferView`][RingBufferView], kept separately
+/// from the view so it can live alongside the storage it describes.
+///
+/// [RingBufferView]: struct.RingBufferView.html
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct RingBufferViewState {
+    origin: usize,
+    length: usize,
+}
+
+impl RingBufferViewState {
+    /// Construct the state of an empty buffer.
+    pub const fn new() -> Self {
+        RingBufferViewState {
+            origin: 0,
+            length: 0,
+        }
+    }
+
+    /// Get the number of values in the buffer this state describes.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Test if the buffer this state describes is empty.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+pub(super) mod sealed {
+    pub trait Sealed {}
+}
+
+/// An index into the storage of a [`RingBufferView`][RingBufferView], which
+/// knows how to wrap around the end of it.
+///
+/// This trait is sealed. The views you construct use `usize`, which wraps
+/// around whatever capacity their storage turns out to have. `RingBuffer` uses
+/// its own index type, which knows its capacity at compile time.
+///
+/// [RingBufferView]: struct.RingBufferView.html
+pub trait ViewIndex: Copy + Eq + sealed::Sealed {
+    #[doc(hidden)]
+    fn from_usize(index: usize) -> Self;
+
+    #[doc(hidden)]
+    fn to_usize(self) -> usize;
+
+    /// Get the index following this one in storage of size `capacity`.
+    #[doc(hidden)]
+    fn next_in(self, capacity: usize) -> Self;
+
+    /// Get the index preceding this one in storage of size `capacity`.
+    #[doc(hidden)]
+    fn prev_in(self, capacity: usize) -> Self;
+
+    /// Get the index `offset` places after this one in storage of size
+    /// `capacity`, where `offset` is at most `capacity`.
+    #[doc(hidden)]
+    fn offset_in(self, offset: usize, capacity: usize) -> Self;
+}
+
+impl sealed::Sealed for usize {}
+
+impl ViewIndex for usize {
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        index
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        self
+    }
+
+    #[inline]
+    fn next_in(self, capacity: usize) -> Self {
+        if self + 1 == capacity {
+            0
+        } else {
+            self + 1
+        }
+    }
+
+    #[inline]
+    fn prev_in(self, capacity: usize) -> Self {
+        if self == 0 {
+            capacity - 1
+        } else {
+            self - 1
+        }
+    }
+
+    #[inline]
+    fn offset_in(self, offset: usize, capacity: usize) -> Self {
+        let index = self + offset;
+        if index >= capacity {
+            index - capacity
+        } else {
+            index
+        }
+    }
+}
+
+/// A [`RingBuffer`][RingBuffer] stored in memory owned by someone else.
+///
+/// A `RingBufferView` works like a `RingBuffer` whose capacity is the length
+/// of the `&mut [MaybeUninit<A>]` slice it's given, and whose bookkeeping is
+/// kept in a separate [`RingBufferViewState`][RingBufferViewState]. It shares
+/// its implementation of pushing, popping, insertion and removal with
+/// `RingBuffer`, and can be indexed and sliced like one, through
+/// [`ViewSlice`][ViewSlice] and [`ViewSliceMut`][ViewSliceMut].
+///
+/// Because the storage and its state outlive the view, dropping a view leaves
+/// the values in it alone. Use [`clear`][clear] to drop them.
+///
+/// The index type `I` is how the view wraps around the end of its storage.
+/// Views you construct always use `usize`; see [`ViewIndex`][ViewIndex].
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::mem::MaybeUninit;
+/// # use sized_chunks::ring_buffer::{RingBufferView, RingBufferViewState};
+/// let mut storage: [MaybeUninit<i32>; 4] = [MaybeUninit::uninit(); 4];
+/// let mut state = RingBufferViewState::new();
+/// let mut view = RingBufferView::new(&mut state, &mut storage);
+/// view.push_back(2);
+/// view.push_back(3);
+/// view.push_front(1);
+/// view.insert(1, 5);
+/// assert_eq!(Some(1), view.pop_front());
+/// assert_eq!(vec![5, 2, 3], view.iter().cloned().collect::<Vec<_>>());
+/// view.clear();
+/// ```
+///
+/// [RingBuffer]: struct.RingBuffer.html
+/// [RingBufferViewState]: struct.RingBufferViewState.html
+/// [ViewSlice]: struct.ViewSlice.html
+/// [ViewSliceMut]: struct.ViewSliceMut.html
+/// [ViewIndex]: trait.ViewIndex.html
+/// [clear]: #method.clear
+pub struct RingBufferView<'a, A, I = usize> {
+    origin: &'a mut I,
+    length: &'a mut usize,
+    data: &'a mut [MaybeUninit<A>],
+}
+
+impl<'a, A> RingBufferView<'a, A> {
+    /// Construct an empty buffer in `data`, resetting `state`.
+    ///
+    /// Any values `state` said were in `data` are forgotten, not dropped.
+    pub fn new(state: &'a mut RingBufferViewState, data: &'a mut [MaybeUninit<A>]) -> Self {
+        *state = RingBufferViewState::new();
+        RingBufferView {
+            origin: &mut state.origin,
+            length: &mut state.length,
+            data,
+        }
+    }
+
+    /// Construct a view of a buffer previously stored in `data` with `state`.
+    ///
+    /// # Safety
+    ///
+    /// `state` must describe a range within `data`, and every value in that
+    /// range must be initialised, as they will be if `state` and `data` were
+    /// last used together by a `RingBufferView`.
+    pub unsafe fn from_parts(
+        state: &'a mut RingBufferViewState,
+        data: &'a mut [MaybeUninit<A>],
+    ) -> Self {
+        check!(
+            state.length <= data.len() && (state.origin < data.len() || state.origin == 0),
+            "RingBufferView::from_parts: state out of bounds for storage"
+        );
+        RingBufferView {
+            origin: &mut state.origin,
+            length: &mut state.length,
+            data,
+        }
+    }
+}
+
+impl<'a, A, I: ViewIndex> RingBufferView<'a, A, I> {
+    /// Construct a view from its raw parts, as used by `RingBuffer`.
+    ///
+    /// # Safety
+    ///
+    /// `length` must not exceed the length of `data`, `origin` must be an
+    /// index into `data` (or 0 if `data` is empty), and the `length` values
+    /// starting from `origin` and wrapping around the end of `data` must be
+    /// initialised.
+    pub(crate) unsafe fn from_raw(
+        origin: &'a mut I,
+        length: &'a mut usize,
+        data: &'a mut [MaybeUninit<A>],
+    ) -> Self {
+        RingBufferView {
+            origin,
+            length,
+            data,
+        }
+    }
+
+    /// Get the raw index following a raw index.
+    #[inline]
+    fn next(&self, index: I) -> I {
+        index.next_in(self.capacity())
+    }
+
+    /// Get the raw index preceding a raw index.
+    #[inline]
+    fn prev(&self, index: I) -> I {
+        index.prev_in(self.capacity())
+    }
+
+    /// Get the raw index for a logical index.
+    #[inline]
+    fn raw(&self, index: usize) -> I {
+        self.origin.offset_in(index, self.capacity())
+    }
+
+    #[inline]
+    fn ptr(&self, index: I) -> *const A {
+        let index = index.to_usize();
+        check!(
+            index < self.capacity(),
+            "RingBufferView::ptr: raw index out of bounds"
+        );
+        self.data.as_ptr().wrapping_add(index).cast()
+    }
+
+    #[inline]
+    fn mut_ptr(&mut self, index: I) -> *mut A {
+        let index = index.to_usize();
+        check!(
+            index < self.capacity(),
+            "RingBufferView::mut_ptr: raw index out of bounds"
+        );
+        self.data.as_mut_ptr().wrapping_add(index).cast()
+    }
+
+    /// Copy the value at a raw index, discarding ownership of the copied value
+    #[inline]
+    unsafe fn force_read(&self, index: I) -> A {
+        ptr::read(self.ptr(index))
+    }
+
+    /// Write a value at a raw index without trying to drop what's already there
+    #[inline]
+    unsafe fn force_write(&mut self, index: I, value: A) {
+        ptr::write(self.mut_ptr(index), value)
+    }
+
+    /// Get the maximum number of values the buffer can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Get the number of values in the buffer.
+    #[inline]
+    pub fn len(&self) -> usize {
+        *self.length
+    }
+
+    /// Test if the buffer is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        *self.length == 0
+    }
+
+    /// Test if the buffer is at capacity.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        *self.length == self.capacity()
+    }
+
+    /// Get a reference to the value at a given index.
+    pub fn get(&self, index: usize) -> Option<&A> {
+        if index >= self.len() {
+            None
+        } else {
+            Some(unsafe { &*self.ptr(self.raw(index)) })
+        }
+    }
+
+    /// Get a mutable reference to the value at a given index.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut A> {
+        if index >= self.len() {
+            None
+        } else {
+            Some(unsafe { &mut *self.mut_ptr(self.raw(index)) })
+        }
+    }
+
+    /// Push a value to the back of the buffer.
+    ///
+    /// Panics if the capacity of the buffer is exceeded.
+    ///
+    /// Time: O(1)
+    pub fn push_back(&mut self, value: A) {
+        if self.is_full() {
+            panic!("RingBufferView::push_back: can't push to a full buffer")
+        }
+        unsafe { self.force_write(self.raw(*self.length), value) }
+        *self.length += 1;
+    }
+
+    /// Push a value to the front of the buffer.
+    ///
+    /// Panics if the capacity of the buffer is exceeded.
+    ///
+    /// Time: O(1)
+    pub fn push_front(&mut self, value: A) {
+        if self.is_full() {
+            panic!("RingBufferView::push_front: can't push to a full buffer")
+        }
+        *self.origin = self.prev(*self.origin);
+        *self.length += 1;
+        unsafe { self.force_write(*self.origin, value) }
+    }
+
+    /// Pop a value from the back of the buffer.
+    ///
+    /// Returns `None` if the buffer is empty.
+    ///
+    /// Time: O(1)
+    pub fn pop_back(&mut self) -> Option<A> {
+        if self.is_empty() {
+            return None;
+        }
+        *self.length -= 1;
+        Some(unsafe { self.force_read(self.raw(*self.length)) })
+    }
+
+    /// Pop a value from the front of the buffer.
+    ///
+    /// Returns `None` if the buffer is empty.
+    ///
+    /// Time: O(1)
+    pub fn pop_front(&mut self) -> Option<A> {
+        if self.is_empty() {
+            return None;
+        }
+        let index = *self.origin;
+        *self.origin = self.next(index);
+        *self.length -= 1;
+        Some(unsafe { self.force_read(index) })
+    }
+
+    /// Insert a new value at index `index`, shifting the values on whichever
+    /// side of it is cheaper to move.
+    ///
+    /// Panics if the index is out of bounds or the buffer is full.
+    ///
+    /// Time: O(n) for the number of items shifted
+    pub fn insert(&mut self, index: usize, value: A) {
+        if self.is_full() {
+            panic!("RingBufferView::insert: chunk size overflow");
+        }
+        if index > self.len() {
+            panic!("RingBufferView::insert: index out of bounds");
+        }
+        if index == 0 {
+            return self.push_front(value);
+        }
+        if index == self.len() {
+            return self.push_back(value);
+        }
+        let right_count = self.len() - index;
+        // Check which side has fewer elements to shift.
+        if right_count < index {
+            // Shift to the right.
+            let mut i = self.raw(self.len() - 1);
+            let target = self.raw(index);
+            while i != target {
+                unsafe { self.force_write(self.next(i), self.force_read(i)) };
+                i = self.prev(i);
+            }
+            unsafe { self.force_write(self.next(target), self.force_read(target)) };
+            *self.length += 1;
+        } else {
+            // Shift to the left.
+            *self.origin = self.prev(*self.origin);
+            *self.length += 1;
+            for offset in 0..index {
+                let i = self.raw(offset);
+                unsafe { self.force_write(i, self.force_read(self.next(i))) };
+            }
+        }
+        unsafe { self.force_write(self.raw(index), value) };
+    }
+
+    /// Insert a new value into the buffer in sorted order.
+    ///
+    /// This assumes every value in the buffer is already in sorted order.
+    ///
+    /// Panics if the buffer is full.
+    ///
+    /// Time: O(log n) to find the insert position, then O(n) for the number
+    /// of elements shifted.
+    pub fn insert_ordered(&mut self, value: A)
+    where
+        A: Ord,
+    {
+        if self.is_full() {
+            panic!("RingBufferView::insert: chunk size overflow");
+        }
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if self.get(middle).unwrap() <= &value {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+        self.insert(low, value)
+    }
+
+    /// Remove the value at index `index`, shifting the values on whichever
+    /// side of it is cheaper to move.
+    ///
+    /// Returns the removed value.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// Time: O(n) for the number of items shifted
+    pub fn remove(&mut self, index: usize) -> A {
+        if index >= self.len() {
+            panic!("RingBufferView::remove: index out of bounds");
+        }
+        let value = unsafe { self.force_read(self.raw(index)) };
+        let right_count = self.len() - index;
+        // Check which side has fewer elements to shift.
+        if right_count < index {
+            // Shift from the right.
+            *self.length -= 1;
+            let mut i = self.raw(index);
+            let target = self.raw(self.len());
+            while i != target {
+                let next = self.next(i);
+                unsafe { self.force_write(i, self.force_read(next)) };
+                i = next;
+            }
+        } else {
+            // Shift from the left.
+            let mut i = self.raw(index);
+            while i != *self.origin {
+                let prev = self.prev(i);
+                unsafe { self.force_write(i, self.force_read(prev)) };
+                i = prev;
+            }
+            *self.origin = self.next(*self.origin);
+            *self.length -= 1;
+        }
+        value
+    }
+
+    /// Drop every value in the buffer.
+    ///
+    /// Time: O(n)
+    pub fn clear(&mut self) {
+        let (left, right) = self.as_mut_slices();
+        let (left, right): (*mut [A], *mut [A]) = (left, right);
+        *self.origin = I::from_usize(0);
+        *self.length = 0;
+        unsafe {
+            ptr::drop_in_place(left);
+            ptr::drop_in_place(right);
+        }
+    }
+
+    /// Get the contents of the buffer as a pair of contiguous slices.
+    ///
+    /// The second slice will be empty unless the contents wrap around the end
+    /// of the storage.
+    pub fn as_slices(&self) -> (&[A], &[A]) {
+        unsafe {
+            split_storage(
+                self.data.as_ptr().cast(),
+                self.capacity(),
+                self.origin.to_usize(),
+                self.len(),
+            )
+        }
+        .as_slices()
+    }
+
+    /// Get the contents of the buffer as a pair of contiguous mutable slices.
+    ///
+    /// The second slice will be empty unless the contents wrap around the end
+    /// of the storage.
+    pub fn as_mut_slices(&mut self) -> (&mut [A], &mut [A]) {
+        let slice = unsafe {
+            split_storage_mut(
+                self.data.as_mut_ptr().cast(),
+                self.capacity(),
+                self.origin.to_usize(),
+                self.len(),
+            )
+        };
+        (slice.left, slice.right)
+    }
+
+    /// Get an iterator over references to the values in the buffer, in order.
+    pub fn iter(&self) -> ViewIter<'_, A> {
+        let (left, right) = self.as_slices();
+        left.iter().chain(right.iter())
+    }
+
+    /// Get an iterator over mutable references to the values in the buffer,
+    /// in order.
+    pub fn iter_mut(&mut self) -> ViewIterMut<'_, A> {
+        let (left, right) = self.as_mut_slices();
+        left.iter_mut().chain(right.iter_mut())
+    }
+
+    /// Get a `ViewSlice` for a subset of the buffer.
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> ViewSlice<'_, A> {
+        let range = parse_range(range, self.len(), "RingBufferView::slice");
+        let (left, right) = self.as_slices();
+        ViewSlice::new(left, right).slice(range)
+    }
+
+    /// Get a `ViewSliceMut` for a subset of the buffer.
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn slice_mut<R: RangeBounds<usize>>(&mut self, range: R) -> ViewSliceMut<'_, A> {
+        let range = parse_range(range, self.len(), "RingBufferView::slice_mut");
+        let (left, right) = self.as_mut_slices();
+        ViewSliceMut::new(left, right).slice(range)
+    }
+}
+
+impl<'a, A, I: ViewIndex> Index<usize> for RingBufferView<'a, A, I> {
+    type Output = A;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "RingBufferView::index: index out of bounds {} >= {}",
+                index,
+                self.len()
+            ),
+        }
+    }
+}
+
+impl<'a, A, I: ViewIndex> IndexMut<usize> for RingBufferView<'a, A, I> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len();
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!(
+                "RingBufferView::index_mut: index out of bounds {} >= {}",
+                index, len
+            ),
+        }
+    }
+}
+
+impl<'a, 'b, A, I: ViewIndex> IntoIterator for &'b RingBufferView<'a, A, I> {
+    type Item = &'b A;
+    type IntoIter = ViewIter<'b, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, 'b, A, I: ViewIndex> IntoIterator for &'b mut RingBufferView<'a, A, I> {
+    type Item = &'b mut A;
+    type IntoIter = ViewIterMut<'b, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<'a, A, I: ViewIndex> Debug for RingBufferView<'a, A, I>
+where
+    A: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("RingBufferView")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Split `length` values of ring storage starting at raw index `start` into
+/// the contiguous slices either side of the end of the storage.
+///
+/// # Safety
+///
+/// `base` must point to `capacity` values, and the `length` values starting
+/// at `start` and wrapping around the end must be initialised and outlive
+/// `'a`.
+pub(super) unsafe fn split_storage<'a, A>(
+    base: *const A,
+    capacity: usize,
+    start: usize,
+    length: usize,
+) -> ViewSlice<'a, A> {
+    if start + length > capacity {
+        let first_length = capacity - start;
+        ViewSlice::new(
+            from_raw_parts(base.add(start), first_length),
+            from_raw_parts(base, length - first_length),
+        )
+    } else {
+        ViewSlice::new(from_raw_parts(base.wrapping_add(start), length), &[])
+    }
+}
+
+/// Split `length` values of ring storage starting at raw index `start` into
+/// the contiguous mutable slices either side of the end of the storage.
+///
+/// # Safety
+///
+/// As for [`split_storage`](fn.split_storage.html), and the values must not
+/// be otherwise borrowed for `'a`.
+pub(super) unsafe fn split_storage_mut<'a, A>(
+    base: *mut A,
+    capacity: usize,
+    start: usize,
+    length: usize,
+) -> ViewSliceMut<'a, A> {
+    if start + length > capacity {
+        let first_length = capacity - start;
+        ViewSliceMut::new(
+            from_raw_parts_mut(base.add(start), first_length),
+            from_raw_parts_mut(base, length - first_length),
+        )
+    } else {
+        ViewSliceMut::new(
+            from_raw_parts_mut(base.wrapping_add(start), length),
+            &mut [],
+        )
+    }
+}
+
+/// Resolve a range against a length, panicking if it's out of bounds.
+pub(super) fn parse_range<R: RangeBounds<usize>>(
+    range: R,
+    len: usize,
+    context: &str,
+) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Unbounded => 0,
+        Bound::Included(index) => *index,
+        Bound::Excluded(index) => *index + 1,
+    };
+    let end = match range.end_bound() {
+        Bound::Unbounded => len,
+        Bound::Included(index) => *index + 1,
+        Bound::Excluded(index) => *index,
+    };
+    if end > len || start > end {
+        panic!("{}: index out of bounds", context);
+    }
+    start..end
+}
+
+/// An indexable representation of a subset of a `RingBufferView`, which
+/// `RingBuffer`'s own `Slice` is also built on.
+///
+/// Like the view it comes from, its contents may wrap around the end of the
+/// storage, so it's made of up to two contiguous slices.
+pub struct ViewSlice<'a, A> {
+    left: &'a [A],
+    right: &'a [A],
+}
+
+impl<'a, A> ViewSlice<'a, A> {
+    fn new(left: &'a [A], right: &'a [A]) -> Self {
+        ViewSlice { left, right }
+    }
+
+    /// Get the length of the slice.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.left.len() + self.right.len()
+    }
+
+    /// Test if the slice is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty() && self.right.is_empty()
+    }
+
+    /// Get a reference to the value at a given index.
+    pub fn get(&self, index: usize) -> Option<&'a A> {
+        if index < self.left.len() {
+            self.left.get(index)
+        } else {
+            self.right.get(index - self.left.len())
+        }
+    }
+
+    /// Get a reference to the first value in the slice.
+    pub fn first(&self) -> Option<&'a A> {
+        self.get(0)
+    }
+
+    /// Get a reference to the last value in the slice.
+    pub fn last(&self) -> Option<&'a A> {
+        if self.is_empty() {
+            None
+        } else {
+            self.get(self.len() - 1)
+        }
+    }
+
+    /// Get an iterator over references to the values in the slice, in order.
+    pub fn iter(&self) -> ViewIter<'a, A> {
+        self.left.iter().chain(self.right.iter())
+    }
+
+    /// Get the contents of the slice as a pair of contiguous slices.
+    ///
+    /// The second slice will be empty unless the contents wrap around the end
+    /// of the storage.
+    pub fn as_slices(&self) -> (&'a [A], &'a [A]) {
+        (self.left, self.right)
+    }
+
+    /// Create a subslice of this slice.
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn slice<R: RangeBounds<usize>>(self, range: R) -> ViewSlice<'a, A> {
+        let range = parse_range(range, self.len(), "ViewSlice::slice");
+        let split = self.left.len();
+        if range.start >= split {
+            ViewSlice::new(&self.right[range.start - split..range.end - split], &[])
+        } else if range.end <= split {
+            ViewSlice::new(&self.left[range], &[])
+        } else {
+            ViewSlice::new(&self.left[range.start..], &self.right[..range.end - split])
+        }
+    }
+
+    /// Split the slice into two subslices at the given index.
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn split_at(self, index: usize) -> (ViewSlice<'a, A>, ViewSlice<'a, A>) {
+        if index > self.len() {
+            panic!("ViewSlice::split_at: index out of bounds");
+        }
+        (self.slice(..index), self.slice(index..))
+    }
+}
+
+impl<'a, A> Clone for ViewSlice<'a, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, A> Copy for ViewSlice<'a, A> {}
+
+impl<'a, A> Index<usize> for ViewSlice<'a, A> {
+    type Output = A;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "ViewSlice::index: index out of bounds {} >= {}",
+                index,
+                self.len()
+            ),
+        }
+    }
+}
+
+impl<'a, A: PartialEq> PartialEq for ViewSlice<'a, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, A: Eq> Eq for ViewSlice<'a, A> {}
+
+impl<'a, A: Hash> Hash for ViewSlice<'a, A> {
+    /// Hash the slice's contents.
+    ///
+    /// This hashes the length followed by the values, like `[A]` does, but
+    /// values wrapping around the end of the storage are passed to
+    /// `A::hash_slice` in two parts, so don't rely on this hashing the same
+    /// as the equivalent `[A]` slice.
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        hasher.write_usize(self.len());
+        A::hash_slice(self.left, hasher);
+        A::hash_slice(self.right, hasher);
+    }
+}
+
+impl<'a, A: Debug> Debug for ViewSlice<'a, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("ViewSlice")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, A> IntoIterator for ViewSlice<'a, A> {
+    type Item = &'a A;
+    type IntoIter = ViewIter<'a, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, A> IntoIterator for &ViewSlice<'a, A> {
+    type Item = &'a A;
+    type IntoIter = ViewIter<'a, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An indexable representation of a mutable subset of a `RingBufferView`,
+/// which `RingBuffer`'s own `SliceMut` is also built on.
+///
+/// Like the view it comes from, its contents may wrap around the end of the
+/// storage, so it's made of up to two contiguous slices.
+pub struct ViewSliceMut<'a, A> {
+    left: &'a mut [A],
+    right: &'a mut [A],
+}
+
+impl<'a, A> ViewSliceMut<'a, A> {
+    fn new(left: &'a mut [A], right: &'a mut [A]) -> Self {
+        ViewSliceMut { left, right }
+    }
+
+    /// Downgrade this slice into a non-mutable slice.
+    pub fn unmut(self) -> ViewSlice<'a, A> {
+        ViewSlice::new(self.left, self.right)
+    }
+
+    /// Get the length of the slice.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.left.len() + self.right.len()
+    }
+
+    /// Test if the slice is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty() && self.right.is_empty()
+    }
+
+    /// Get a reference to the value at a given index.
+    pub fn get(&self, index: usize) -> Option<&A> {
+        if index < self.left.len() {
+            self.left.get(index)
+        } else {
+            self.right.get(index - self.left.len())
+        }
+    }
+
+    /// Get a mutable reference to the value at a given index.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut A> {
+        if index < self.left.len() {
+            self.left.get_mut(index)
+        } else {
+            self.right.get_mut(index - self.left.len())
+        }
+    }
+
+    /// Get a reference to the first value in the slice.
+    pub fn first(&self) -> Option<&A> {
+        self.get(0)
+    }
+
+    /// Get a reference to the last value in the slice.
+    pub fn last(&self) -> Option<&A> {
+        if self.is_empty() {
+            None
+        } else {
+            self.get(self.len() - 1)
+        }
+    }
+
+    /// Get an iterator over references to the values in the slice, in order.
+    pub fn iter(&self) -> ViewIter<'_, A> {
+        self.left.iter().chain(self.right.iter())
+    }
+
+    /// Get an iterator over mutable references to the values in the slice,
+    /// in order.
+    pub fn iter_mut(&mut self) -> ViewIterMut<'_, A> {
+        self.left.iter_mut().chain(self.right.iter_mut())
+    }
+
+    /// Get the contents of the slice as a pair of contiguous slices.
+    ///
+    /// The second slice will be empty unless the contents wrap around the end
+    /// of the storage.
+    pub fn as_slices(&self) -> (&[A], &[A]) {
+        (self.left, self.right)
+    }
+
+    /// Get the contents of the slice as a pair of contiguous mutable slices.
+    ///
+    /// The second slice will be empty unless the contents wrap around the end
+    /// of the storage.
+    pub fn as_mut_slices(&mut self) -> (&mut [A], &mut [A]) {
+        (self.left, self.right)
+    }
+
+    /// Create a subslice of this slice.
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn slice<R: RangeBounds<usize>>(self, range: R) -> ViewSliceMut<'a, A> {
+        let range = parse_range(range, self.len(), "ViewSliceMut::slice");
+        let split = self.left.len();
+        if range.start >= split {
+            ViewSliceMut::new(
+                &mut self.right[range.start - split..range.end - split],
+                &mut [],
+            )
+        } else if range.end <= split {
+            ViewSliceMut::new(&mut self.left[range], &mut [])
+        } else {
+            ViewSliceMut::new(
+                &mut self.left[range.start..],
+                &mut self.right[..range.end - split],
+            )
+        }
+    }
+
+    /// Split the slice into two subslices at the given index.
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn split_at(self, index: usize) -> (ViewSliceMut<'a, A>, ViewSliceMut<'a, A>) {
+        if index > self.len() {
+            panic!("ViewSliceMut::split_at: index out of bounds");
+        }
+        let split = self.left.len();
+        if index >= split {
+            let (middle, right) = self.right.split_at_mut(index - split);
+            (
+                ViewSliceMut::new(self.left, middle),
+                ViewSliceMut::new(right, &mut []),
+            )
+        } else {
+            let (left, middle) = self.left.split_at_mut(index);
+            (
+                ViewSliceMut::new(left, &mut []),
+                ViewSliceMut::new(middle, self.right),
+            )
+        }
+    }
+}
+
+impl<'a, A> From<ViewSliceMut<'a, A>> for ViewSlice<'a, A> {
+    fn from(slice: ViewSliceMut<'a, A>) -> Self {
+        slice.unmut()
+    }
+}
+
+impl<'a, A> Index<usize> for ViewSliceMut<'a, A> {
+    type Output = A;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "ViewSliceMut::index: index out of bounds {} >= {}",
+                index,
+                self.len()
+            ),
+        }
+    }
+}
+
+impl<'a, A> IndexMut<usize> for ViewSliceMut<'a, A> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len();
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!(
+                "ViewSliceMut::index_mut: index out of bounds {} >= {}",
+                index, len
+            ),
+        }
+    }
+}
+
+impl<'a, A: PartialEq> PartialEq for ViewSliceMut<'a, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, A: Eq> Eq for ViewSliceMut<'a, A> {}
+
+impl<'a, A: Hash> Hash for ViewSliceMut<'a, A> {
+    /// Hash the slice's contents, the same way as
+    /// [`ViewSlice`](struct.ViewSlice.html) does.
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        hasher.write_usize(self.len());
+        A::hash_slice(self.left, hasher);
+        A::hash_slice(self.right, hasher);
+    }
+}
+
+impl<'a, A: Debug> Debug for ViewSliceMut<'a, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("ViewSliceMut")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, A> IntoIterator for ViewSliceMut<'a, A> {
+    type Item = &'a mut A;
+    type IntoIter = ViewIterMut<'a, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.left.iter_mut().chain(self.right.iter_mut())
+    }
+}
+
+impl<'a, 'b, A> IntoIterator for &'b ViewSliceMut<'a, A> {
+    type Item = &'b A;
+    type IntoIter = ViewIter<'b, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, 'b, A> IntoIterator for &'b mut ViewSliceMut<'a, A> {
+    type Item = &'b mut A;
+    type IntoIter = ViewIterMut<'b, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tests::DropTest;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn insert_remove_across_wrap() {
+        let mut storage: [MaybeUninit<i32>; 5] = [MaybeUninit::uninit(); 5];
+        let mut state = RingBufferViewState::new();
+        let mut view = RingBufferView::new(&mut state, &mut storage);
+        view.push_back(3);
+        view.push_front(1);
+        view.push_front(0);
+        view.insert(2, 2);
+        view.insert_ordered(4);
+        assert!(view.is_full());
+        assert_eq!(
+            vec![0, 1, 2, 3, 4],
+            view.iter().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(3, view.remove(3));
+        assert_eq!(1, view.remove(1));
+        assert_eq!(Some(0), view.pop_front());
+        assert_eq!(Some(4), view.pop_back());
+        assert_eq!(Some(&2), view.get(0));
+        assert_eq!(None, view.get(1));
+    }
+
+    #[test]
+    fn slices_across_wrap() {
+        let mut storage: [MaybeUninit<i32>; 6] = [MaybeUninit::uninit(); 6];
+        let mut state = RingBufferViewState::new();
+        let mut view = RingBufferView::new(&mut state, &mut storage);
+        for i in 0..3 {
+            view.push_back(i + 3);
+            view.push_front(2 - i);
+        }
+        assert_eq!(3, view[3]);
+        view[3] = 30;
+        for value in &mut view {
+            *value += 1;
+        }
+        assert_eq!(
+            vec![1, 2, 3, 31, 5, 6],
+            (&view).into_iter().cloned().collect::<Vec<_>>()
+        );
+        let slice = view.slice(1..5);
+        assert_eq!(4, slice.len());
+        assert_eq!((Some(&2), Some(&5)), (slice.first(), slice.last()));
+        let (left, right) = slice.split_at(2);
+        assert_eq!(vec![2, 3], left.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(vec![31, 5], right.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(view.slice(3..=3), slice.slice(2..3));
+        assert!(view.slice(6..).is_empty());
+        let mut slice = view.slice_mut(2..);
+        slice[0] = 0;
+        let (left, mut right) = slice.split_at(1);
+        assert_eq!(1, left.len());
+        right[2] = 0;
+        assert_eq!(
+            vec![1, 2, 0, 31, 5, 0],
+            view.iter().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "RingBufferView::slice: index out of bounds")]
+    fn slice_out_of_bounds() {
+        let mut storage: [MaybeUninit<i32>; 4] = [MaybeUninit::uninit(); 4];
+        let mut state = RingBufferViewState::new();
+        let mut view = RingBufferView::new(&mut state, &mut storage);
+        view.push_back(1);
+        let _ = view.slice(..2);
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "checked"))]
+    #[should_panic(expected = "RingBufferView::ptr: raw index out of bounds")]
+    fn ptr_out_of_bounds() {
+        let mut storage: [MaybeUninit<i32>; 4] = [MaybeUninit::uninit(); 4];
+        let mut state = RingBufferViewState::new();
+        let view = RingBufferView::new(&mut state, &mut storage);
+        let _ = view.ptr(4);
+    }
+
+    #[test]
+    fn values_outlive_view() {
+        let counter = AtomicUsize::new(0);
+        let mut storage: [MaybeUninit<DropTest<'_>>; 3] = [
+            MaybeUninit::uninit(),
+            MaybeUninit::uninit(),
+            MaybeUninit::uninit(),
+        ];
+        let mut state = RingBufferViewState::new();
+        {
+            let mut view = RingBufferView::new(&mut state, &mut storage);
+            view.push_back(DropTest::new(&counter));
+            view.push_front(DropTest::new(&counter));
+        }
+        assert_eq!(2, counter.load(Ordering::Relaxed));
+        let mut view = unsafe { RingBufferView::from_parts(&mut state, &mut storage) };
+        assert_eq!(2, view.len());
+        view.clear();
+        assert_eq!(0, counter.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,40 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use ::zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::types::ChunkLength;
+use crate::RingBuffer;
+
+impl<A, N> Zeroize for RingBuffer<A, N>
+where
+    N: ChunkLength<A>,
+{
+    /// Drop the contents of the buffer and overwrite its entire backing store,
+    /// not just the occupied span, with zeroes.
+    fn zeroize(&mut self) {
+        self.clear();
+        crate::types::zeroize_storage(&mut self.data);
+    }
+}
+
+impl<A, N> ZeroizeOnDrop for RingBuffer<A, N> where N: ChunkLength<A> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use typenum::U32;
+
+    #[test]
+    fn zeroize_wipes_full_capacity() {
+        let mut buffer: RingBuffer<u8, U32> = (1..=32).collect();
+        buffer.drain().take(20).for_each(drop);
+        buffer.extend(1..=10);
+        buffer.zeroize();
+        assert!(buffer.is_empty());
+        let storage =
+            unsafe { std::slice::from_raw_parts(&buffer.data as *const _ as *const u8, 32) };
+        assert!(storage.iter().all(|byte| *byte == 0));
+    }
+}
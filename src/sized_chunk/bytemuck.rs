@@ -0,0 +1,120 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+
+use ::bytemuck::{cast_slice, Pod, PodCastError, Zeroable};
+
+use crate::types::ChunkLength;
+use crate::Chunk;
+
+impl<A, N> Chunk<A, N>
+where
+    A: Pod,
+    N: ChunkLength<A>,
+{
+    /// Get the contents of the chunk as a slice of bytes.
+    ///
+    /// This is only available with the `bytemuck` feature enabled.
+    pub fn as_bytes(&self) -> &[u8] {
+        cast_slice(self.as_slice())
+    }
+
+    /// Construct a chunk by copying its contents out of a slice of bytes.
+    ///
+    /// The bytes don't need to be aligned for `A`. Fails with
+    /// [`PodCastError::OutputSliceWouldHaveSlop`][PodCastError] if the length
+    /// of `bytes` isn't a multiple of the size of `A`, or with
+    /// [`PodCastError::SizeMismatch`][PodCastError] if the values won't fit in
+    /// the chunk.
+    ///
+    /// This is only available with the `bytemuck` feature enabled.
+    ///
+    /// [PodCastError]: https://docs.rs/bytemuck/latest/bytemuck/enum.PodCastError.html
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, PodCastError> {
+        let len = pod_len::<A>(bytes, Self::CAPACITY)?;
+        let mut chunk = Self::new();
+        unsafe {
+            ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                &mut chunk.data as *mut _ as *mut u8,
+                bytes.len(),
+            )
+        }
+        chunk.right = len;
+        Ok(chunk)
+    }
+}
+
+impl<A, N> Chunk<A, N>
+where
+    A: Zeroable,
+    N: ChunkLength<A>,
+{
+    /// Construct a full chunk where every value is zeroed.
+    ///
+    /// This is only available with the `bytemuck` feature enabled.
+    pub fn zeroed() -> Self {
+        Self {
+            left: 0,
+            right: N::USIZE,
+            data: MaybeUninit::zeroed(),
+        }
+    }
+}
+
+/// Count the values of type `A` in `bytes`, if there's a whole number of them
+/// and no more than `capacity`.
+pub(crate) fn pod_len<A>(bytes: &[u8], capacity: usize) -> Result<usize, PodCastError> {
+    let size = mem::size_of::<A>();
+    let len = match size {
+        0 if bytes.is_empty() => 0,
+        0 => return Err(PodCastError::SizeMismatch),
+        _ => bytes.len() / size,
+    };
+    if len * size != bytes.len() {
+        return Err(PodCastError::OutputSliceWouldHaveSlop);
+    }
+    if len > capacity {
+        return Err(PodCastError::SizeMismatch);
+    }
+    Ok(len)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use typenum::U4;
+
+    #[test]
+    fn bytes() {
+        let chunk: Chunk<u16, U4> = Chunk::from([0x0102, 0x0304]);
+        let bytes = chunk.as_bytes().to_vec();
+        assert_eq!(4, bytes.len());
+        let copy = Chunk::<u16, U4>::try_from_bytes(&bytes).unwrap();
+        assert_eq!(chunk, copy);
+        // The copy must work from unaligned input too.
+        let mut shifted = vec![0];
+        shifted.extend(&bytes);
+        assert_eq!(
+            chunk,
+            Chunk::<u16, U4>::try_from_bytes(&shifted[1..]).unwrap()
+        );
+        assert_eq!(
+            Err(PodCastError::OutputSliceWouldHaveSlop),
+            Chunk::<u16, U4>::try_from_bytes(&[1, 2, 3])
+        );
+        assert_eq!(
+            Err(PodCastError::SizeMismatch),
+            Chunk::<u16, U4>::try_from_bytes(&[0; 10])
+        );
+    }
+
+    #[test]
+    fn zeroed() {
+        let chunk: Chunk<u32, U4> = Chunk::zeroed();
+        assert_eq!(&[0, 0, 0, 0], chunk.as_slice());
+    }
+}
@@ -0,0 +1,101 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use ::bytes::buf::UninitSlice;
+use ::bytes::{Buf, BufMut};
+
+use crate::types::ChunkLength;
+use crate::Chunk;
+
+impl<N> Buf for Chunk<u8, N>
+where
+    N: ChunkLength<u8>,
+{
+    fn remaining(&self) -> usize {
+        self.len()
+    }
+
+    fn chunk(&self) -> &[u8] {
+        self.as_slice()
+    }
+
+    /// Discard `count` bytes from the front of the chunk.
+    ///
+    /// Panics if the chunk holds fewer than `count` bytes.
+    ///
+    /// Time: O(1)
+    fn advance(&mut self, count: usize) {
+        if count > self.len() {
+            panic!("Chunk::advance: index out of bounds");
+        }
+        self.left += count;
+    }
+}
+
+unsafe impl<N> BufMut for Chunk<u8, N>
+where
+    N: ChunkLength<u8>,
+{
+    fn remaining_mut(&self) -> usize {
+        N::USIZE - self.len()
+    }
+
+    /// Add `count` bytes written through [`chunk_mut`][chunk_mut] to the back
+    /// of the chunk.
+    ///
+    /// Panics if there isn't room for `count` more bytes at the back of the
+    /// chunk.
+    ///
+    /// [chunk_mut]: #method.chunk_mut
+    unsafe fn advance_mut(&mut self, count: usize) {
+        if count > N::USIZE - self.right {
+            panic!("Chunk::advance_mut: chunk size overflow");
+        }
+        self.right += count;
+    }
+
+    /// Get the free space at the back of the chunk.
+    ///
+    /// If the back of the chunk is at the end of its storage but there's free
+    /// space at the front, the contents are first moved to the front.
+    ///
+    /// Time: O(n) for the number of bytes moved
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        if self.right == N::USIZE && self.left > 0 {
+            let len = self.len();
+            unsafe { Chunk::force_copy(self.left, 0, len, self) };
+            self.left = 0;
+            self.right = len;
+        }
+        let free = N::USIZE - self.right;
+        unsafe { UninitSlice::from_raw_parts_mut(self.mut_ptr(self.right), free) }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use typenum::U8;
+
+    #[test]
+    fn buf_and_buf_mut() {
+        let mut chunk: Chunk<u8, U8> = Chunk::new();
+        chunk.put_u32(0x0102_0304);
+        chunk.put_slice(&[5, 6, 7, 8]);
+        assert_eq!(0, chunk.remaining_mut());
+        assert_eq!(0x0102, chunk.get_u16());
+        assert_eq!(&[3, 4, 5, 6, 7, 8], chunk.chunk());
+        assert_eq!(2, chunk.chunk_mut().len());
+        assert_eq!(&[3, 4, 5, 6, 7, 8], chunk.as_slice());
+        chunk.put_u16(0x090a);
+        assert_eq!(&[3, 4, 5, 6, 7, 8, 9, 10], chunk.as_slice());
+    }
+
+    #[test]
+    #[should_panic(expected = "Chunk::advance: index out of bounds")]
+    fn advance_past_end() {
+        let mut chunk: Chunk<u8, U8> = (1..=3).collect();
+        chunk.advance(4);
+    }
+}
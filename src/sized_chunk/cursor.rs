@@ -0,0 +1,245 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use super::Chunk;
+use crate::types::ChunkLength;
+
+/// A cursor over a `Chunk`.
+///
+/// A cursor points either at an element of the chunk, or at a "ghost"
+/// position past the end of the chunk, which sits between the last and the
+/// first elements, so moving past either end of the chunk wraps around through
+/// the ghost position, like the cursors of `std::collections::LinkedList`.
+pub struct Cursor<'a, A, N>
+where
+    N: ChunkLength<A>,
+{
+    chunk: &'a Chunk<A, N>,
+    index: usize,
+}
+
+impl<'a, A, N> Clone for Cursor<'a, A, N>
+where
+    N: ChunkLength<A>,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, A, N> Copy for Cursor<'a, A, N> where N: ChunkLength<A> {}
+
+impl<'a, A, N> Cursor<'a, A, N>
+where
+    N: ChunkLength<A>,
+{
+    pub(crate) fn new(chunk: &'a Chunk<A, N>) -> Self {
+        Cursor { chunk, index: 0 }
+    }
+
+    /// Get the index of the cursor's element, or `None` if the cursor is at
+    /// the ghost position.
+    pub fn index(&self) -> Option<usize> {
+        if self.index < self.chunk.len() {
+            Some(self.index)
+        } else {
+            None
+        }
+    }
+
+    /// Get the cursor's element, or `None` if the cursor is at the ghost
+    /// position.
+    pub fn current(&self) -> Option<&'a A> {
+        self.chunk.as_slice().get(self.index)
+    }
+
+    /// Get the element after the cursor's element.
+    ///
+    /// At the ghost position, this is the first element of the chunk.
+    pub fn peek_next(&self) -> Option<&'a A> {
+        let index = if self.index == self.chunk.len() {
+            0
+        } else {
+            self.index + 1
+        };
+        self.chunk.as_slice().get(index)
+    }
+
+    /// Get the element before the cursor's element.
+    ///
+    /// At the ghost position, this is the last element of the chunk.
+    pub fn peek_prev(&self) -> Option<&'a A> {
+        if self.index == 0 {
+            return None;
+        }
+        self.chunk.as_slice().get(self.index - 1)
+    }
+
+    /// Move the cursor to the next element.
+    ///
+    /// From the last element, this moves to the ghost position, and from the
+    /// ghost position, it moves to the first element.
+    pub fn move_next(&mut self) {
+        self.index = if self.index == self.chunk.len() {
+            0
+        } else {
+            self.index + 1
+        };
+    }
+
+    /// Move the cursor to the previous element.
+    ///
+    /// From the first element, this moves to the ghost position, and from the
+    /// ghost position, it moves to the last element.
+    pub fn move_prev(&mut self) {
+        self.index = if self.index == 0 {
+            self.chunk.len()
+        } else {
+            self.index - 1
+        };
+    }
+}
+
+/// A cursor over a `Chunk` which can edit the chunk at the cursor's position.
+///
+/// See [`Cursor`][Cursor] for how a cursor moves.
+///
+/// [Cursor]: struct.Cursor.html
+pub struct CursorMut<'a, A, N>
+where
+    N: ChunkLength<A>,
+{
+    chunk: &'a mut Chunk<A, N>,
+    index: usize,
+}
+
+impl<'a, A, N> CursorMut<'a, A, N>
+where
+    N: ChunkLength<A>,
+{
+    pub(crate) fn new(chunk: &'a mut Chunk<A, N>) -> Self {
+        CursorMut { chunk, index: 0 }
+    }
+
+    /// Get a read only cursor at the same position as this one.
+    pub fn as_cursor(&self) -> Cursor<'_, A, N> {
+        Cursor {
+            chunk: self.chunk,
+            index: self.index,
+        }
+    }
+
+    /// Get the index of the cursor's element, or `None` if the cursor is at
+    /// the ghost position.
+    pub fn index(&self) -> Option<usize> {
+        self.as_cursor().index()
+    }
+
+    /// Get a mutable reference to the cursor's element, or `None` if the
+    /// cursor is at the ghost position.
+    pub fn current(&mut self) -> Option<&mut A> {
+        self.chunk.as_mut_slice().get_mut(self.index)
+    }
+
+    /// Get a mutable reference to the element after the cursor's element.
+    ///
+    /// At the ghost position, this is the first element of the chunk.
+    pub fn peek_next(&mut self) -> Option<&mut A> {
+        let index = if self.index == self.chunk.len() {
+            0
+        } else {
+            self.index + 1
+        };
+        self.chunk.as_mut_slice().get_mut(index)
+    }
+
+    /// Get a mutable reference to the element before the cursor's element.
+    ///
+    /// At the ghost position, this is the last element of the chunk.
+    pub fn peek_prev(&mut self) -> Option<&mut A> {
+        if self.index == 0 {
+            return None;
+        }
+        self.chunk.as_mut_slice().get_mut(self.index - 1)
+    }
+
+    /// Move the cursor to the next element.
+    ///
+    /// From the last element, this moves to the ghost position, and from the
+    /// ghost position, it moves to the first element.
+    pub fn move_next(&mut self) {
+        self.index = if self.index == self.chunk.len() {
+            0
+        } else {
+            self.index + 1
+        };
+    }
+
+    /// Move the cursor to the previous element.
+    ///
+    /// From the first element, this moves to the ghost position, and from the
+    /// ghost position, it moves to the last element.
+    pub fn move_prev(&mut self) {
+        self.index = if self.index == 0 {
+            self.chunk.len()
+        } else {
+            self.index - 1
+        };
+    }
+
+    /// Insert a value before the cursor's element, or at the back of the
+    /// chunk if the cursor is at the ghost position.
+    ///
+    /// The cursor stays on the same element.
+    ///
+    /// Panics if the chunk is full.
+    ///
+    /// Time: O(n) for the number of elements shifted
+    pub fn insert_before(&mut self, value: A) {
+        self.chunk.insert(self.index, value);
+        self.index += 1;
+    }
+
+    /// Insert a value after the cursor's element, or at the front of the
+    /// chunk if the cursor is at the ghost position.
+    ///
+    /// The cursor stays on the same element.
+    ///
+    /// Panics if the chunk is full.
+    ///
+    /// Time: O(n) for the number of elements shifted
+    pub fn insert_after(&mut self, value: A) {
+        if self.index == self.chunk.len() {
+            self.chunk.insert(0, value);
+            self.index += 1;
+        } else {
+            self.chunk.insert(self.index + 1, value);
+        }
+    }
+
+    /// Remove the cursor's element and return it, moving the cursor to the
+    /// next element.
+    ///
+    /// Returns `None`, without changing anything, if the cursor is at the
+    /// ghost position.
+    ///
+    /// Time: O(n) for the number of elements shifted
+    pub fn remove_current(&mut self) -> Option<A> {
+        if self.index == self.chunk.len() {
+            None
+        } else {
+            Some(self.chunk.remove(self.index))
+        }
+    }
+
+    /// Split the chunk at the cursor, returning a new chunk containing the
+    /// cursor's element and everything after it.
+    ///
+    /// The cursor is left at the ghost position of the original chunk.
+    ///
+    /// Time: O(n) for the number of elements in the new chunk
+    pub fn split_at_cursor(&mut self) -> Chunk<A, N> {
+        self.chunk.split_off(self.index)
+    }
+}
@@ -7,29 +7,51 @@
 //! See [`Chunk`](struct.Chunk.html)
 
 use crate::inline_array::InlineArray;
+use crate::ring_buffer::{RingBuffer, Slice as RingSlice, SliceMut as RingSliceMut};
 use std::borrow::{Borrow, BorrowMut};
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::fmt::{Debug, Error, Formatter};
 use std::hash::{Hash, Hasher};
 use std::io;
 use std::iter::FromIterator;
-use std::mem::{replace, MaybeUninit};
-use std::ops::{Deref, DerefMut, Index, IndexMut};
+use std::mem::{self, replace, ManuallyDrop, MaybeUninit};
+use std::ops::{Add, Deref, DerefMut, Index, IndexMut, Sub};
 use std::ptr;
 use std::slice::{
     from_raw_parts, from_raw_parts_mut, Iter as SliceIter, IterMut as SliceIterMut, SliceIndex,
 };
 
-use typenum::U64;
+use typenum::{Diff, Sum, U64};
 
-use crate::types::ChunkLength;
+use crate::types::{ChunkLength, DoubleEndedFixedSeq, FixedSeq};
+
+mod cursor;
+pub use self::cursor::{Cursor, CursorMut};
 
 mod iter;
 pub use self::iter::{Drain, Iter};
 
+mod view;
+pub use self::view::{ChunkView, ChunkViewState};
+
 #[cfg(feature = "refpool")]
 mod refpool;
 
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+#[cfg(feature = "bytes")]
+mod bytes;
+
+#[cfg(feature = "bytemuck")]
+pub(crate) mod bytemuck;
+
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "bytemuck")]
+pub use self::pod::{PodChunk, PodElement};
+
 /// A fixed capacity smart array.
 ///
 /// An inline array of items with a variable length but a fixed, preallocated
@@ -112,6 +134,36 @@ where
 {
     fn drop(&mut self) {
         unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+        #[cfg(feature = "zeroize")]
+        crate::types::zeroize_storage(&mut self.data);
+    }
+}
+
+/// The gap `Chunk::insert_from` opens for the values it's inserting.
+///
+/// Dropping it closes whatever's left of the gap and fixes up the chunk's
+/// bounds, so the chunk stays valid even if the iterator panics or yields
+/// fewer values than it said it would.
+struct InsertGap<'a, A, N>
+where
+    N: ChunkLength<A>,
+{
+    chunk: &'a mut Chunk<A, N>,
+    left: usize,
+    right: usize,
+    next: usize,
+    remaining: usize,
+}
+
+impl<'a, A, N> Drop for InsertGap<'a, A, N>
+where
+    N: ChunkLength<A>,
+{
+    fn drop(&mut self) {
+        let tail = self.next + self.remaining;
+        unsafe { Chunk::force_copy(tail, self.next, self.right - tail, self.chunk) };
+        self.chunk.left = self.left;
+        self.chunk.right = self.right - self.remaining;
     }
 }
 
@@ -123,9 +175,12 @@ where
     fn clone(&self) -> Self {
         let mut out = Self::new();
         out.left = self.left;
-        out.right = self.right;
+        out.right = self.left;
+        // Only count each value once it's been cloned, so that if a clone
+        // panics, `out` drops the values cloned so far and nothing else.
         for index in self.left..self.right {
             unsafe { Chunk::force_write(index, (*self.ptr(index)).clone(), &mut out) }
+            out.right += 1;
         }
         out
     }
@@ -147,6 +202,60 @@ where
         }
     }
 
+    /// Construct a new empty chunk in place.
+    ///
+    /// This only initialises the chunk's bookkeeping, leaving its storage
+    /// untouched, so it's cheap however large the chunk is, and the chunk
+    /// never needs to exist on the stack.
+    pub fn init_in_place(target: &mut MaybeUninit<Self>) -> &mut Self {
+        unsafe {
+            let ptr = target.as_mut_ptr();
+            ptr::addr_of_mut!((*ptr).left).write(0);
+            ptr::addr_of_mut!((*ptr).right).write(0);
+            &mut *ptr
+        }
+    }
+
+    /// Construct a new empty chunk directly on the heap.
+    ///
+    /// Unlike `Box::new(Chunk::new())`, this never builds the chunk on the
+    /// stack first, so it's safe to use for chunks too large to fit there.
+    pub fn new_boxed() -> Box<Self> {
+        let mut boxed = crate::boxed::new_uninit::<Self>();
+        Self::init_in_place(&mut boxed);
+        unsafe { crate::boxed::assume_init(boxed) }
+    }
+
+    /// Clone the chunk directly onto the heap.
+    ///
+    /// See [`new_boxed`](#method.new_boxed).
+    pub fn clone_boxed(&self) -> Box<Self>
+    where
+        A: Clone,
+    {
+        let mut out = Self::new_boxed();
+        for value in self.iter() {
+            out.push_back(value.clone());
+        }
+        out
+    }
+
+    /// Construct a chunk directly on the heap from an iterator.
+    ///
+    /// See [`new_boxed`](#method.new_boxed).
+    ///
+    /// Panics if the iterator yields more values than the chunk can hold.
+    pub fn collect_boxed<I>(iter: I) -> Box<Self>
+    where
+        I: IntoIterator<Item = A>,
+    {
+        let mut out = Self::new_boxed();
+        for value in iter {
+            out.push_back(value);
+        }
+        out
+    }
+
     /// Construct a new chunk with one item.
     pub fn unit(value: A) -> Self {
         let mut chunk = Self {
@@ -210,7 +319,7 @@ where
     /// Time: O(n) for the number of items moved
     pub fn from_front(other: &mut Self, count: usize) -> Self {
         let other_len = other.len();
-        debug_assert!(count <= other_len);
+        check!(count <= other_len, "Chunk::from_front: index out of bounds");
         let mut chunk = Self::new();
         unsafe { Chunk::force_copy_to(other.left, 0, count, other, &mut chunk) };
         chunk.right = count;
@@ -224,7 +333,7 @@ where
     /// Time: O(n) for the number of items moved
     pub fn from_back(other: &mut Self, count: usize) -> Self {
         let other_len = other.len();
-        debug_assert!(count <= other_len);
+        check!(count <= other_len, "Chunk::from_back: index out of bounds");
         let mut chunk = Self::new();
         unsafe { Chunk::force_copy_to(other.right - count, 0, count, other, &mut chunk) };
         chunk.right = count;
@@ -232,6 +341,45 @@ where
         chunk
     }
 
+    /// Construct a new chunk by cloning the contents of `values`, placing the
+    /// first value at the physical position `offset`.
+    ///
+    /// This leaves room for `offset` calls to `push_front` and
+    /// `Self::CAPACITY - offset - values.len()` calls to `push_back` before
+    /// the chunk needs to shift its contents around.
+    ///
+    /// Panics if `values` doesn't fit in the chunk at the given offset.
+    ///
+    /// Time: O(n) for the number of items cloned
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sized_chunks::Chunk;
+    /// # use typenum::U8;
+    /// let chunk = Chunk::<i32, U8>::from_slice_at(3, &[1, 2]);
+    /// assert_eq!(3, chunk.left_capacity());
+    /// assert_eq!(3, chunk.right_capacity());
+    /// ```
+    pub fn from_slice_at(offset: usize, values: &[A]) -> Self
+    where
+        A: Clone,
+    {
+        if values.len() > Self::CAPACITY || offset > Self::CAPACITY - values.len() {
+            panic!("Chunk::from_slice_at: index out of bounds");
+        }
+        let mut chunk = Self {
+            left: offset,
+            right: offset,
+            data: MaybeUninit::uninit(),
+        };
+        for value in values {
+            unsafe { Chunk::force_write(chunk.right, value.clone(), &mut chunk) };
+            chunk.right += 1;
+        }
+        chunk
+    }
+
     /// Get the length of the chunk.
     #[inline]
     pub fn len(&self) -> usize {
@@ -250,6 +398,63 @@ where
         self.left == 0 && self.right == Self::CAPACITY
     }
 
+    /// Get the number of free slots in front of the first item.
+    ///
+    /// This is how many times you can `push_front` before the chunk has to
+    /// shift its contents to make room.
+    #[inline]
+    pub fn left_capacity(&self) -> usize {
+        self.left
+    }
+
+    /// Get the number of free slots after the last item.
+    ///
+    /// This is how many times you can `push_back` before the chunk has to
+    /// shift its contents to make room.
+    #[inline]
+    pub fn right_capacity(&self) -> usize {
+        N::USIZE - self.right
+    }
+
+    /// Shift the contents of the chunk so that the free space is divided
+    /// evenly between the front and the back.
+    ///
+    /// Use this when you expect to be pushing to both ends of the chunk, to
+    /// avoid a shift for every change of direction. Note that an empty chunk
+    /// still repositions itself for the first item pushed into it, so this is
+    /// only useful on a chunk which has contents.
+    ///
+    /// Time: O(n) for the number of items shifted
+    pub fn recenter(&mut self) {
+        self.move_to((N::USIZE - self.len()) / 2)
+    }
+
+    /// Shift the contents of the chunk all the way to the front, leaving all
+    /// the free space at the back.
+    ///
+    /// Time: O(n) for the number of items shifted
+    pub fn align_left(&mut self) {
+        self.move_to(0)
+    }
+
+    /// Shift the contents of the chunk all the way to the back, leaving all
+    /// the free space at the front.
+    ///
+    /// Time: O(n) for the number of items shifted
+    pub fn align_right(&mut self) {
+        self.move_to(N::USIZE - self.len())
+    }
+
+    /// Shift the contents of the chunk so the first item is at `left`.
+    fn move_to(&mut self, left: usize) {
+        if left != self.left {
+            let len = self.len();
+            unsafe { Chunk::force_copy(self.left, left, len, self) };
+            self.left = left;
+            self.right = left + len;
+        }
+    }
+
     #[inline]
     unsafe fn ptr(&self, index: usize) -> *const A {
         (&self.data as *const _ as *const A).add(index)
@@ -260,10 +465,30 @@ where
         (&mut self.data as *mut _ as *mut A).add(index)
     }
 
-    /// Copy the value at an index, discarding ownership of the copied value
-    #[inline]
-    unsafe fn force_read(index: usize, chunk: &mut Self) -> A {
-        chunk.ptr(index).read()
+    /// Empty the chunk without dropping its values, and return a slice of
+    /// them. The caller takes ownership of the values in the slice.
+    #[cfg(feature = "rayon")]
+    pub(crate) unsafe fn take_values(&mut self) -> &mut [A] {
+        let (left, right) = (self.left, self.right);
+        self.left = 0;
+        self.right = 0;
+        from_raw_parts_mut(self.mut_ptr(left), right - left)
+    }
+
+    /// Empty the chunk and get its whole storage as uninitialised values, to
+    /// be written from the front and then claimed with `set_len`.
+    #[cfg(feature = "rayon")]
+    pub(crate) unsafe fn uninit_storage(&mut self) -> &mut [MaybeUninit<A>] {
+        self.left = 0;
+        self.right = 0;
+        from_raw_parts_mut(self.mut_ptr(0).cast(), N::USIZE)
+    }
+
+    /// Claim the first `len` values written through `uninit_storage`.
+    #[cfg(feature = "rayon")]
+    pub(crate) unsafe fn set_len(&mut self, len: usize) {
+        self.left = 0;
+        self.right = len;
     }
 
     /// Write a value at an index without trying to drop what's already there
@@ -272,6 +497,19 @@ where
         chunk.mut_ptr(index).write(value)
     }
 
+    /// Get a view of the chunk's storage, for sharing the implementation of
+    /// editing operations with `ChunkView`.
+    #[inline]
+    fn view(&mut self) -> ChunkView<'_, A> {
+        let data = unsafe {
+            std::slice::from_raw_parts_mut(
+                &mut self.data as *mut _ as *mut MaybeUninit<A>,
+                N::USIZE,
+            )
+        };
+        unsafe { ChunkView::from_raw(&mut self.left, &mut self.right, data) }
+    }
+
     /// Copy a range within a chunk
     #[inline]
     unsafe fn force_copy(from: usize, to: usize, count: usize, chunk: &mut Self) {
@@ -303,16 +541,7 @@ where
         if self.is_full() {
             panic!("Chunk::push_front: can't push to full chunk");
         }
-        if self.is_empty() {
-            self.left = N::USIZE;
-            self.right = N::USIZE;
-        } else if self.left == 0 {
-            self.left = N::USIZE - self.right;
-            unsafe { Chunk::force_copy(0, self.left, self.right, self) };
-            self.right = N::USIZE;
-        }
-        self.left -= 1;
-        unsafe { Chunk::force_write(self.left, value, self) }
+        self.view().push_front(value)
     }
 
     /// Push an item to the back of the chunk.
@@ -324,16 +553,7 @@ where
         if self.is_full() {
             panic!("Chunk::push_back: can't push to full chunk");
         }
-        if self.is_empty() {
-            self.left = 0;
-            self.right = 0;
-        } else if self.right == N::USIZE {
-            unsafe { Chunk::force_copy(self.left, 0, self.len(), self) };
-            self.right = N::USIZE - self.left;
-            self.left = 0;
-        }
-        unsafe { Chunk::force_write(self.right, value, self) }
-        self.right += 1;
+        self.view().push_back(value)
     }
 
     /// Pop an item off the front of the chunk.
@@ -344,11 +564,8 @@ where
     pub fn pop_front(&mut self) -> A {
         if self.is_empty() {
             panic!("Chunk::pop_front: can't pop from empty chunk");
-        } else {
-            let value = unsafe { Chunk::force_read(self.left, self) };
-            self.left += 1;
-            value
         }
+        self.view().pop_front()
     }
 
     /// Pop an item off the back of the chunk.
@@ -359,10 +576,8 @@ where
     pub fn pop_back(&mut self) -> A {
         if self.is_empty() {
             panic!("Chunk::pop_back: can't pop from empty chunk");
-        } else {
-            self.right -= 1;
-            unsafe { Chunk::force_read(self.right, self) }
         }
+        self.view().pop_back()
     }
 
     /// Discard all items up to but not including `index`.
@@ -372,8 +587,9 @@ where
     /// Time: O(n) for the number of items dropped
     pub fn drop_left(&mut self, index: usize) {
         if index > 0 {
-            unsafe { ptr::drop_in_place(&mut self[..index]) }
+            let dropped: *mut [A] = &mut self[..index];
             self.left += index;
+            unsafe { ptr::drop_in_place(dropped) }
         }
     }
 
@@ -384,8 +600,9 @@ where
     /// Time: O(n) for the number of items dropped
     pub fn drop_right(&mut self, index: usize) {
         if index != self.len() {
-            unsafe { ptr::drop_in_place(&mut self[index..]) }
+            let dropped: *mut [A] = &mut self[index..];
             self.right = self.left + index;
+            unsafe { ptr::drop_in_place(dropped) }
         }
     }
 
@@ -412,6 +629,100 @@ where
         right_chunk
     }
 
+    /// Move the contents of the chunk onto the back of a chunk of another
+    /// size.
+    ///
+    /// `target` must have room at the back for every value in the chunk.
+    unsafe fn move_to_back<M>(mut self, target: &mut Chunk<A, M>)
+    where
+        M: ChunkLength<A>,
+    {
+        let len = self.len();
+        ptr::copy_nonoverlapping(self.ptr(self.left), target.mut_ptr(target.right), len);
+        target.right += len;
+        self.left = 0;
+        self.right = 0;
+    }
+
+    /// Concatenate two chunks into a chunk big enough to hold both of them.
+    ///
+    /// Time: O(n) for the number of items moved
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::iter::FromIterator;
+    /// # use sized_chunks::Chunk;
+    /// # use typenum::{U3, U4, U7};
+    /// let left = Chunk::<i32, U3>::from_iter(0..3);
+    /// let right = Chunk::<i32, U4>::from_iter(3..5);
+    /// let chunk: Chunk<i32, U7> = left.concat(right);
+    /// assert_eq!(&[0, 1, 2, 3, 4], chunk.as_slice());
+    /// ```
+    pub fn concat<M>(self, other: Chunk<A, M>) -> Chunk<A, Sum<N, M>>
+    where
+        M: ChunkLength<A>,
+        N: Add<M>,
+        Sum<N, M>: ChunkLength<A>,
+    {
+        let mut out = Chunk::new();
+        unsafe {
+            self.move_to_back(&mut out);
+            other.move_to_back(&mut out);
+        }
+        out
+    }
+
+    /// Split a chunk into a chunk of capacity `K` holding its first `K` items
+    /// and a chunk holding whatever's left over.
+    ///
+    /// Time: O(n) for the number of items moved
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::iter::FromIterator;
+    /// # use sized_chunks::Chunk;
+    /// # use typenum::{U2, U3, U5};
+    /// let chunk = Chunk::<i32, U5>::from_iter(0..4);
+    /// let (left, right) = chunk.split_at_type::<U2>();
+    /// let right: Chunk<i32, U3> = right;
+    /// assert_eq!(&[0, 1], left.as_slice());
+    /// assert_eq!(&[2, 3], right.as_slice());
+    /// ```
+    pub fn split_at_type<K>(mut self) -> (Chunk<A, K>, Chunk<A, Diff<N, K>>)
+    where
+        K: ChunkLength<A>,
+        N: Sub<K>,
+        Diff<N, K>: ChunkLength<A>,
+    {
+        let right = self.split_off(K::USIZE.min(self.len()));
+        let mut left_out = Chunk::new();
+        let mut right_out = Chunk::new();
+        unsafe {
+            self.move_to_back(&mut left_out);
+            right.move_to_back(&mut right_out);
+        }
+        (left_out, right_out)
+    }
+
+    /// Move the contents of the chunk into a chunk of capacity `M`.
+    ///
+    /// Returns the chunk unchanged as an error if it has more than `M` items.
+    ///
+    /// Time: O(n) for the number of items moved
+    pub fn resize_capacity<M>(self) -> Result<Chunk<A, M>, Self>
+    where
+        M: ChunkLength<A>,
+    {
+        if self.len() > M::USIZE {
+            return Err(self);
+        }
+        let mut out = Chunk::new();
+        unsafe { self.move_to_back(&mut out) };
+        Ok(out)
+    }
+
     /// Remove all items from `other` and append them to the back of `self`.
     ///
     /// Panics if the capacity of the chunk is exceeded.
@@ -478,6 +789,69 @@ where
         other.right -= count;
     }
 
+    /// Move items between `self` and its right sibling `other` until their
+    /// lengths differ by at most one.
+    ///
+    /// The items keep their order, reading from the front of `self` to the
+    /// back of `other`.
+    ///
+    /// Time: O(n) for the number of items moved
+    pub fn balance_with(&mut self, other: &mut Self) {
+        let self_len = self.len();
+        let other_len = other.len();
+        if self_len > other_len + 1 {
+            other.drain_from_back(self, (self_len - other_len) / 2);
+        } else if other_len > self_len + 1 {
+            self.drain_from_front(other, (other_len - self_len) / 2);
+        }
+    }
+
+    /// Append all items from `other` to the back of `self` if they fit,
+    /// leaving `other` empty.
+    ///
+    /// Returns `false`, leaving both untouched, if they don't fit.
+    ///
+    /// Time: O(n) for the number of items moved
+    pub fn merge_if_fits(&mut self, other: &mut Self) -> bool {
+        if self.len() + other.len() > N::USIZE {
+            false
+        } else {
+            self.append(other);
+            true
+        }
+    }
+
+    /// Move `count` items from the back of `siblings[index - 1]` to the front
+    /// of `siblings[index]`.
+    ///
+    /// Panics if `index` is zero or out of bounds, if the left sibling has
+    /// fewer than `count` items, or if `siblings[index]` doesn't have room for
+    /// them.
+    ///
+    /// Time: O(n) for the number of items moved
+    pub fn steal_from_left(siblings: &mut [Self], index: usize, count: usize) {
+        if index == 0 || index >= siblings.len() {
+            panic!("Chunk::steal_from_left: index out of bounds");
+        }
+        let (left, right) = siblings.split_at_mut(index);
+        right[0].drain_from_back(&mut left[index - 1], count);
+    }
+
+    /// Move `count` items from the front of `siblings[index + 1]` to the back
+    /// of `siblings[index]`.
+    ///
+    /// Panics if `siblings[index + 1]` is out of bounds, if it has fewer than
+    /// `count` items, or if `siblings[index]` doesn't have room for them.
+    ///
+    /// Time: O(n) for the number of items moved
+    pub fn steal_from_right(siblings: &mut [Self], index: usize, count: usize) {
+        if index + 1 >= siblings.len() {
+            panic!("Chunk::steal_from_right: index out of bounds");
+        }
+        let (left, right) = siblings.split_at_mut(index + 1);
+        left[index].drain_from_front(&mut right[0], count);
+    }
+
     /// Update the value at index `index`, returning the old value.
     ///
     /// Panics if `index` is out of bounds.
@@ -500,22 +874,7 @@ where
         if index > self.len() {
             panic!("Chunk::insert: index out of bounds");
         }
-        let real_index = index + self.left;
-        let left_size = index;
-        let right_size = self.right - real_index;
-        if self.right == N::USIZE || (self.left > 0 && left_size < right_size) {
-            unsafe {
-                Chunk::force_copy(self.left, self.left - 1, left_size, self);
-                Chunk::force_write(real_index - 1, value, self);
-            }
-            self.left -= 1;
-        } else {
-            unsafe {
-                Chunk::force_copy(real_index, real_index + 1, right_size, self);
-                Chunk::force_write(real_index, value, self);
-            }
-            self.right += 1;
-        }
+        self.view().insert(index, value)
     }
 
     /// Insert a new value into the chunk in sorted order.
@@ -575,42 +934,38 @@ where
         if index > self.len() {
             panic!("Chunk::insert_from: index out of bounds");
         }
+        let len = self.len();
         let real_index = index + self.left;
         let left_size = index;
         let right_size = self.right - real_index;
-        if self.right == N::USIZE || (self.left >= insert_size && left_size < right_size) {
-            unsafe {
-                Chunk::force_copy(self.left, self.left - insert_size, left_size, self);
-                let mut write_index = real_index - insert_size;
-                for value in iter {
-                    Chunk::force_write(write_index, value, self);
-                    write_index += 1;
-                }
-            }
-            self.left -= insert_size;
+        // Open a gap for the new values, and work out where the chunk will
+        // start once it's filled.
+        let (left, gap) = if self.right == N::USIZE
+            || (self.left >= insert_size && left_size < right_size)
+        {
+            unsafe { Chunk::force_copy(self.left, self.left - insert_size, left_size, self) };
+            (self.left - insert_size, real_index - insert_size)
         } else if self.left == 0 || (self.right + insert_size <= Self::CAPACITY) {
-            unsafe {
-                Chunk::force_copy(real_index, real_index + insert_size, right_size, self);
-                let mut write_index = real_index;
-                for value in iter {
-                    Chunk::force_write(write_index, value, self);
-                    write_index += 1;
-                }
-            }
-            self.right += insert_size;
+            unsafe { Chunk::force_copy(real_index, real_index + insert_size, right_size, self) };
+            (self.left, real_index)
         } else {
             unsafe {
                 Chunk::force_copy(self.left, 0, left_size, self);
                 Chunk::force_copy(real_index, left_size + insert_size, right_size, self);
-                let mut write_index = left_size;
-                for value in iter {
-                    Chunk::force_write(write_index, value, self);
-                    write_index += 1;
-                }
             }
-            self.right -= self.left;
-            self.right += insert_size;
-            self.left = 0;
+            (0, left_size)
+        };
+        let mut gap = InsertGap {
+            chunk: self,
+            left,
+            right: left + len + insert_size,
+            next: gap,
+            remaining: insert_size,
+        };
+        for value in iter.take(insert_size) {
+            unsafe { Chunk::force_write(gap.next, value, gap.chunk) };
+            gap.next += 1;
+            gap.remaining -= 1;
         }
     }
 
@@ -626,18 +981,7 @@ where
         if index >= self.len() {
             panic!("Chunk::remove: index out of bounds");
         }
-        let real_index = index + self.left;
-        let value = unsafe { Chunk::force_read(real_index, self) };
-        let left_size = index;
-        let right_size = self.right - real_index - 1;
-        if left_size < right_size {
-            unsafe { Chunk::force_copy(self.left, self.left + 1, left_size, self) };
-            self.left += 1;
-        } else {
-            unsafe { Chunk::force_copy(real_index + 1, real_index, right_size, self) };
-            self.right -= 1;
-        }
-        value
+        self.view().remove(index)
     }
 
     /// Construct an iterator that drains values from the front of the chunk.
@@ -645,13 +989,25 @@ where
         Drain { chunk: self }
     }
 
+    /// Get a cursor pointing at the first element of the chunk.
+    pub fn cursor(&self) -> Cursor<'_, A, N> {
+        Cursor::new(self)
+    }
+
+    /// Get a cursor pointing at the first element of the chunk, which can
+    /// insert and remove elements at its position.
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, A, N> {
+        CursorMut::new(self)
+    }
+
     /// Discard the contents of the chunk.
     ///
     /// Time: O(n)
     pub fn clear(&mut self) {
-        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+        let dropped: *mut [A] = self.as_mut_slice();
         self.left = 0;
         self.right = 0;
+        unsafe { ptr::drop_in_place(dropped) }
     }
 
     /// Get a reference to the contents of the chunk as a slice.
@@ -721,13 +1077,15 @@ where
     A: Hash,
     N: ChunkLength<A>,
 {
+    /// Hash the chunk's contents.
+    ///
+    /// This produces exactly the same hash as the equivalent `[A]` slice, as
+    /// required by the `Borrow<[A]>` implementation.
     fn hash<H>(&self, hasher: &mut H)
     where
         H: Hasher,
     {
-        for item in self {
-            item.hash(hasher)
-        }
+        self.as_slice().hash(hasher)
     }
 }
 
@@ -742,6 +1100,39 @@ where
     }
 }
 
+impl<A, N, M> PartialEq<RingBuffer<A, M>> for Chunk<A, N>
+where
+    A: PartialEq,
+    N: ChunkLength<A>,
+    M: ChunkLength<A>,
+{
+    fn eq(&self, other: &RingBuffer<A, M>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<A, N, M> PartialEq<RingSlice<'_, A, M>> for Chunk<A, N>
+where
+    A: PartialEq,
+    N: ChunkLength<A>,
+    M: ChunkLength<A>,
+{
+    fn eq(&self, other: &RingSlice<'_, A, M>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<A, N, M> PartialEq<RingSliceMut<'_, A, M>> for Chunk<A, N>
+where
+    A: PartialEq,
+    N: ChunkLength<A>,
+    M: ChunkLength<A>,
+{
+    fn eq(&self, other: &RingSliceMut<'_, A, M>) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
 impl<A, N> Eq for Chunk<A, N>
 where
     A: Eq,
@@ -749,12 +1140,46 @@ where
 {
 }
 
-impl<A, N> PartialOrd for Chunk<A, N>
+impl<A, N, Slice> PartialOrd<Slice> for Chunk<A, N>
+where
+    Slice: Borrow<[A]>,
+    A: PartialOrd,
+    N: ChunkLength<A>,
+{
+    fn partial_cmp(&self, other: &Slice) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.borrow())
+    }
+}
+
+impl<A, N, M> PartialOrd<RingBuffer<A, M>> for Chunk<A, N>
+where
+    A: PartialOrd,
+    N: ChunkLength<A>,
+    M: ChunkLength<A>,
+{
+    fn partial_cmp(&self, other: &RingBuffer<A, M>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<A, N, M> PartialOrd<RingSlice<'_, A, M>> for Chunk<A, N>
+where
+    A: PartialOrd,
+    N: ChunkLength<A>,
+    M: ChunkLength<A>,
+{
+    fn partial_cmp(&self, other: &RingSlice<'_, A, M>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<A, N, M> PartialOrd<RingSliceMut<'_, A, M>> for Chunk<A, N>
 where
     A: PartialOrd,
     N: ChunkLength<A>,
+    M: ChunkLength<A>,
 {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    fn partial_cmp(&self, other: &RingSliceMut<'_, A, M>) -> Option<Ordering> {
         self.iter().partial_cmp(other.iter())
     }
 }
@@ -802,17 +1227,27 @@ impl<A, N, T> From<InlineArray<A, T>> for Chunk<A, N>
 where
     N: ChunkLength<A>,
 {
+    /// Move the contents of an `InlineArray` into a new chunk.
+    ///
+    /// Panics if the array holds more items than the chunk has room for.
     #[inline]
     fn from(mut array: InlineArray<A, T>) -> Self {
         Self::from(&mut array)
     }
 }
 
-impl<'a, A, N, T> From<&'a mut InlineArray<A, T>> for Chunk<A, N>
+impl<A, N, T> From<&mut InlineArray<A, T>> for Chunk<A, N>
 where
     N: ChunkLength<A>,
 {
+    /// Move the contents of an `InlineArray` into a new chunk, leaving the
+    /// array empty.
+    ///
+    /// Panics if the array holds more items than the chunk has room for.
     fn from(array: &mut InlineArray<A, T>) -> Self {
+        if array.len() > Self::CAPACITY {
+            panic!("Chunk::from: chunk size overflow");
+        }
         let mut out = Self::new();
         out.left = 0;
         out.right = array.len();
@@ -824,6 +1259,86 @@ where
     }
 }
 
+impl<A, N> From<RingBuffer<A, N>> for Chunk<A, N>
+where
+    N: ChunkLength<A>,
+{
+    /// Move the contents of a `RingBuffer` into a new chunk.
+    ///
+    /// Time: O(n) for a bulk copy of the buffer's contents
+    fn from(buffer: RingBuffer<A, N>) -> Self {
+        let mut out = Self::new();
+        let (left, right) = buffer.slices(0..buffer.len());
+        unsafe {
+            ptr::copy_nonoverlapping(left.as_ptr(), out.mut_ptr(0), left.len());
+            ptr::copy_nonoverlapping(right.as_ptr(), out.mut_ptr(left.len()), right.len());
+        }
+        out.right = buffer.len();
+        mem::forget(buffer);
+        out
+    }
+}
+
+impl<A, N, const K: usize> From<[A; K]> for Chunk<A, N>
+where
+    N: ChunkLength<A>,
+{
+    /// Move the contents of an array into a new chunk.
+    ///
+    /// It's a compile time error if `K` is larger than the chunk's capacity.
+    fn from(array: [A; K]) -> Self {
+        const { assert!(K <= N::USIZE, "Chunk::from: chunk size overflow") };
+        let array = ManuallyDrop::new(array);
+        let mut out = Self::new();
+        unsafe { ptr::copy_nonoverlapping(array.as_ptr(), out.mut_ptr(0), K) };
+        out.right = K;
+        out
+    }
+}
+
+impl<A, N> TryFrom<Vec<A>> for Chunk<A, N>
+where
+    N: ChunkLength<A>,
+{
+    type Error = Vec<A>;
+
+    /// Move the contents of a `Vec` into a new chunk.
+    ///
+    /// Fails and gives you the `Vec` back if it's too large to fit in the
+    /// chunk.
+    fn try_from(mut vec: Vec<A>) -> Result<Self, Self::Error> {
+        if vec.len() > Self::CAPACITY {
+            return Err(vec);
+        }
+        let mut out = Self::new();
+        unsafe {
+            ptr::copy_nonoverlapping(vec.as_ptr(), out.mut_ptr(0), vec.len());
+            out.right = vec.len();
+            vec.set_len(0);
+        }
+        Ok(out)
+    }
+}
+
+impl<'a, A, N> TryFrom<&'a [A]> for Chunk<A, N>
+where
+    A: Clone,
+    N: ChunkLength<A>,
+{
+    type Error = &'a [A];
+
+    /// Construct a new chunk by cloning the contents of a slice.
+    ///
+    /// Fails and gives you the slice back if it's too large to fit in the
+    /// chunk.
+    fn try_from(slice: &'a [A]) -> Result<Self, Self::Error> {
+        if slice.len() > Self::CAPACITY {
+            return Err(slice);
+        }
+        Ok(slice.iter().cloned().collect())
+    }
+}
+
 impl<A, N> Borrow<[A]> for Chunk<A, N>
 where
     N: ChunkLength<A>,
@@ -969,6 +1484,76 @@ where
     }
 }
 
+impl<A, N> FixedSeq for Chunk<A, N>
+where
+    N: ChunkLength<A>,
+{
+    type Item = A;
+    type Iter<'a>
+        = SliceIter<'a, A>
+    where
+        Self: 'a;
+    type Drain<'a>
+        = Drain<'a, A, N>
+    where
+        Self: 'a;
+
+    const CAPACITY: usize = N::USIZE;
+
+    fn len(&self) -> usize {
+        Chunk::len(self)
+    }
+
+    fn push_back(&mut self, value: A) {
+        Chunk::push_back(self, value)
+    }
+
+    fn pop_back(&mut self) -> Option<A> {
+        if Chunk::is_empty(self) {
+            None
+        } else {
+            Some(Chunk::pop_back(self))
+        }
+    }
+
+    fn get(&self, index: usize) -> Option<&A> {
+        self.as_slice().get(index)
+    }
+
+    fn insert(&mut self, index: usize, value: A) {
+        Chunk::insert(self, index, value)
+    }
+
+    fn remove(&mut self, index: usize) -> A {
+        Chunk::remove(self, index)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.as_slice().iter()
+    }
+
+    fn drain(&mut self) -> Self::Drain<'_> {
+        Chunk::drain(self)
+    }
+}
+
+impl<A, N> DoubleEndedFixedSeq for Chunk<A, N>
+where
+    N: ChunkLength<A>,
+{
+    fn push_front(&mut self, value: A) {
+        Chunk::push_front(self, value)
+    }
+
+    fn pop_front(&mut self) -> Option<A> {
+        if Chunk::is_empty(self) {
+            None
+        } else {
+            Some(Chunk::pop_front(self))
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -977,10 +1562,10 @@ mod test {
     fn is_full() {
         let mut chunk = Chunk::<_, U64>::new();
         for i in 0..64 {
-            assert_eq!(false, chunk.is_full());
+            assert!(!chunk.is_full());
             chunk.push_back(i);
         }
-        assert_eq!(true, chunk.is_full());
+        assert!(chunk.is_full());
     }
 
     #[test]
@@ -1157,8 +1742,182 @@ mod test {
         assert_eq!(should_vec, out_vec);
     }
 
-    use crate::tests::DropTest;
+    #[test]
+    fn compare_across_types() {
+        let chunk: Chunk<i32, U64> = (0..16).collect();
+        let buffer: RingBuffer<i32, U64> = (0..16).collect();
+        let array: InlineArray<i32, [usize; 32]> = (0..16).collect();
+        assert_eq!(chunk, buffer);
+        assert_eq!(chunk, buffer.slice(..));
+        assert_eq!(chunk, array);
+        assert_eq!(array, buffer);
+        assert_eq!(buffer, chunk);
+        assert_eq!(buffer, array);
+        let smaller: RingBuffer<i32, U64> = (0..15).collect();
+        assert!(chunk > smaller);
+        assert!(chunk > smaller.slice(..));
+        assert!(array > smaller);
+        assert!(smaller < chunk);
+        assert!(smaller.slice(..) < array);
+        assert!(chunk.partial_cmp(&array) == Some(std::cmp::Ordering::Equal));
+    }
+
+    #[test]
+    fn hash_matches_slice() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashMap;
+        fn hash_of<H: Hash + ?Sized>(value: &H) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+        let chunk: Chunk<u8, U64> = (0..16).collect();
+        let slice: &[u8] = &chunk;
+        assert_eq!(hash_of(slice), hash_of(&chunk));
+        let mut map = HashMap::new();
+        map.insert(chunk.clone(), "chunk");
+        assert_eq!(Some(&"chunk"), map.get(slice));
+    }
+
+    #[test]
+    fn conversions() {
+        let mut buffer: RingBuffer<i32, U64> = (32..64).collect();
+        for i in (0..32).rev() {
+            buffer.push_front(i);
+        }
+        let chunk = Chunk::from(buffer);
+        assert_eq!(chunk, (0..64).collect::<Vec<_>>());
+        let chunk: Chunk<i32, U64> = Chunk::from([1, 2, 3]);
+        assert_eq!(chunk, [1, 2, 3]);
+        let chunk = Chunk::<i32, U64>::try_from(vec![1, 2, 3]).unwrap();
+        assert_eq!(chunk, [1, 2, 3]);
+        let too_big: Vec<i32> = (0..65).collect();
+        assert_eq!(Err(too_big.clone()), Chunk::<i32, U64>::try_from(too_big));
+        let slice: &[i32] = &[1, 2, 3];
+        assert_eq!(chunk, Chunk::<i32, U64>::try_from(slice).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Chunk::from_slice_at: index out of bounds")]
+    fn from_slice_at_huge_offset() {
+        let _ = Chunk::<i32, U64>::from_slice_at(usize::MAX, &[1]);
+    }
+
+    #[test]
+    fn layout_control() {
+        let mut chunk = Chunk::<_, U64>::from_slice_at(10, &[1, 2, 3]);
+        assert_eq!(10, chunk.left_capacity());
+        assert_eq!(51, chunk.right_capacity());
+        chunk.recenter();
+        assert_eq!(30, chunk.left_capacity());
+        assert_eq!(31, chunk.right_capacity());
+        for i in 0..30 {
+            chunk.push_front(-i);
+            chunk.push_back(i);
+        }
+        assert_eq!(0, chunk.left_capacity());
+        assert_eq!(1, chunk.right_capacity());
+        chunk.drop_left(20);
+        chunk.drop_right(20);
+        chunk.align_left();
+        assert_eq!(0, chunk.left_capacity());
+        assert_eq!(44, chunk.right_capacity());
+        chunk.align_right();
+        assert_eq!(44, chunk.left_capacity());
+        assert_eq!(0, chunk.right_capacity());
+        let expected: Vec<i32> = (-9..=0).chain(1..4).chain(0..7).collect();
+        assert_eq!(chunk, expected);
+    }
+
+    use crate::tests::{assert_panics, DropTest, PanicOnClone, TestIter};
     use std::sync::atomic::{AtomicUsize, Ordering};
+    use typenum::U8;
+
+    #[test]
+    fn type_level_resizing() {
+        use typenum::{U3, U5};
+        let chunk = Chunk::<i32, U5>::from_iter(0..5);
+        let (left, right) = chunk.split_at_type::<U3>();
+        assert_eq!(&[0, 1, 2], left.as_slice());
+        assert_eq!(&[3, 4], right.as_slice());
+        let chunk: Chunk<i32, U5> = right.concat(left);
+        assert_eq!(&[3, 4, 0, 1, 2], chunk.as_slice());
+        let chunk = chunk.resize_capacity::<U3>().unwrap_err();
+        let chunk = chunk.resize_capacity::<U8>().unwrap();
+        assert_eq!(&[3, 4, 0, 1, 2], chunk.as_slice());
+
+        let counter = AtomicUsize::new(0);
+        {
+            let mut chunk: Chunk<DropTest<'_>, U5> = Chunk::new();
+            for _ in 0..4 {
+                chunk.push_front(DropTest::new(&counter));
+            }
+            let (left, right) = chunk.split_at_type::<U3>();
+            let chunk = left.concat(right).resize_capacity::<U8>().ok().unwrap();
+            assert_eq!(4, chunk.len());
+            assert_eq!(4, counter.load(Ordering::Relaxed));
+        }
+        assert_eq!(0, counter.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn panic_safety() {
+        let mut chunk = Chunk::<i32, U8>::from_iter(0..4);
+        assert_panics(|| chunk.insert_from(2, TestIter::panic_after(10..13, 3, 2)));
+        assert_eq!(&[0, 1, 10, 11, 2, 3], chunk.as_slice());
+        chunk.insert_from(1, TestIter::stop_after(20..22, 2, 1));
+        assert_eq!(&[0, 20, 1, 10, 11, 2, 3], chunk.as_slice());
+
+        let counter = AtomicUsize::new(0);
+        {
+            let mut chunk: Chunk<PanicOnClone<'_>, U8> = Chunk::new();
+            chunk.push_back(PanicOnClone::new(&counter));
+            chunk.push_back(PanicOnClone::panicking(&counter));
+            assert_panics(|| drop(chunk.clone()));
+            assert_eq!(2, counter.load(Ordering::Relaxed));
+
+            chunk.push_front(PanicOnClone::new(&counter));
+            let values: Vec<_> = (0..3).map(|_| PanicOnClone::new(&counter)).collect();
+            assert_panics(|| chunk.insert_from(1, TestIter::panic_after(values.into_iter(), 3, 2)));
+            assert_eq!(5, chunk.len());
+            assert_eq!(5, counter.load(Ordering::Relaxed));
+        }
+        assert_eq!(0, counter.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "checked"))]
+    #[should_panic(expected = "Chunk::from_front: index out of bounds")]
+    fn from_front_beyond_len() {
+        let mut chunk = Chunk::<i32, U8>::from_iter(0..4);
+        let _ = Chunk::from_front(&mut chunk, 5);
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "checked"))]
+    #[should_panic(expected = "Chunk::from_back: index out of bounds")]
+    fn from_back_beyond_len() {
+        let mut chunk = Chunk::<i32, U8>::from_iter(0..4);
+        let _ = Chunk::from_back(&mut chunk, 5);
+    }
+
+    #[test]
+    fn dropping_conversions() {
+        let counter = AtomicUsize::new(0);
+        {
+            let vec: Vec<_> = (0..20).map(|_| DropTest::new(&counter)).collect();
+            let chunk: Chunk<DropTest<'_>> = Chunk::try_from(vec).ok().unwrap();
+            assert_eq!(20, counter.load(Ordering::Relaxed));
+            let buffer = RingBuffer::from(chunk);
+            assert_eq!(20, counter.load(Ordering::Relaxed));
+            let chunk = Chunk::from(buffer);
+            assert_eq!(20, counter.load(Ordering::Relaxed));
+            let array: InlineArray<_, [usize; 32]> = InlineArray::try_from(chunk).ok().unwrap();
+            assert_eq!(20, counter.load(Ordering::Relaxed));
+            drop(array);
+        }
+        assert_eq!(0, counter.load(Ordering::Relaxed));
+    }
 
     #[test]
     fn dropping() {
@@ -1179,4 +1938,109 @@ mod test {
         }
         assert_eq!(0, counter.load(Ordering::Relaxed));
     }
+
+    #[test]
+    fn rebalance_siblings() {
+        let mut left: Chunk<i32, U8> = (0..8).collect();
+        let mut right: Chunk<i32, U8> = Chunk::unit(8);
+        left.balance_with(&mut right);
+        assert_eq!((5, 4), (left.len(), right.len()));
+        assert!(!left.merge_if_fits(&mut right));
+        let mut siblings = [left, right, Chunk::new()];
+        Chunk::steal_from_right(&mut siblings, 0, 1);
+        Chunk::steal_from_left(&mut siblings, 2, 2);
+        Chunk::steal_from_left(&mut siblings, 1, 3);
+        let lens: Vec<_> = siblings.iter().map(|chunk| chunk.len()).collect();
+        assert_eq!(vec![3, 4, 2], lens);
+        let [mut left, mut middle, mut right] = siblings;
+        assert!(middle.merge_if_fits(&mut right));
+        assert!(right.is_empty());
+        left.balance_with(&mut middle);
+        assert_eq!((4, 5), (left.len(), middle.len()));
+        let all: Vec<_> = left.iter().chain(middle.iter()).cloned().collect();
+        assert_eq!((0..9).collect::<Vec<_>>(), all);
+    }
+
+    #[test]
+    fn cursor_peeking() {
+        let mut chunk: Chunk<i32, U8> = (1..4).collect();
+        let mut cursor = chunk.cursor();
+        assert_eq!((None, Some(&2)), (cursor.peek_prev(), cursor.peek_next()));
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!((Some(&2), None), (cursor.peek_prev(), cursor.peek_next()));
+        cursor.move_next();
+        assert_eq!(
+            (Some(&3), Some(&1)),
+            (cursor.peek_prev(), cursor.peek_next())
+        );
+        let mut cursor = chunk.cursor_mut();
+        assert_eq!(None, cursor.peek_prev());
+        assert_eq!(Some(&mut 2), cursor.peek_next());
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(Some(&mut 2), cursor.peek_prev());
+        assert_eq!(None, cursor.peek_next());
+        cursor.move_next();
+        assert_eq!(Some(&mut 3), cursor.peek_prev());
+        assert_eq!(Some(&mut 1), cursor.peek_next());
+    }
+
+    #[test]
+    fn cursor_editing() {
+        let mut chunk: Chunk<i32, U8> = (1..5).collect();
+        {
+            let mut cursor = chunk.cursor_mut();
+            cursor.move_next();
+            assert_eq!(Some(&mut 2), cursor.current());
+            cursor.insert_before(10);
+            cursor.insert_after(20);
+            assert_eq!(Some(2), cursor.index());
+            assert_eq!(Some(2), cursor.remove_current());
+            assert_eq!(Some(&mut 20), cursor.current());
+            cursor.move_prev();
+            cursor.move_prev();
+            cursor.move_prev();
+            assert_eq!(None, cursor.index());
+            assert_eq!(Some(&mut 4), cursor.peek_prev());
+            cursor.insert_after(0);
+            cursor.insert_before(5);
+            assert_eq!(None, cursor.current());
+        }
+        let expected: Vec<i32> = vec![0, 1, 10, 20, 3, 4, 5];
+        assert_eq!(expected, chunk.iter().cloned().collect::<Vec<_>>());
+        let mut cursor = chunk.cursor();
+        let mut backwards = Vec::new();
+        cursor.move_prev();
+        assert_eq!(None, cursor.current());
+        cursor.move_prev();
+        while let Some(value) = cursor.current() {
+            backwards.push(*value);
+            cursor.move_prev();
+        }
+        assert_eq!(
+            expected.iter().rev().cloned().collect::<Vec<_>>(),
+            backwards
+        );
+        let mut cursor = chunk.cursor_mut();
+        for _ in 0..3 {
+            cursor.move_next();
+        }
+        let rest = cursor.split_at_cursor();
+        assert_eq!(None, cursor.current());
+        assert_eq!(vec![20, 3, 4, 5], rest.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(3, chunk.len());
+    }
+
+    #[test]
+    fn boxed_chunks_skip_the_stack() {
+        // A 128k chunk on a 64k stack would overflow if it ever touched it.
+        crate::tests::on_small_stack(|| {
+            let mut chunk = Chunk::<u64, typenum::U16384>::new_boxed();
+            chunk.push_back(1);
+            chunk.push_front(0);
+            assert_eq!(&[0, 1], chunk.clone_boxed().as_slice());
+            assert!(Chunk::<u64, typenum::U16384>::collect_boxed(0..16384).is_full());
+        });
+    }
 }
@@ -0,0 +1,306 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::fmt::{Debug, Error, Formatter};
+use std::iter::FromIterator;
+use std::ops::{Deref, DerefMut, Rem};
+use std::slice::{from_raw_parts, from_raw_parts_mut};
+
+use ::bytemuck::{Pod, Zeroable};
+use typenum::{U0, U8};
+
+use crate::types::ChunkLength;
+use crate::Chunk;
+
+/// Element types which can be stored in a [`PodChunk`][PodChunk].
+///
+/// This is implemented for the primitive integer and floating point types.
+///
+/// This is only available with the `bytemuck` feature enabled.
+///
+/// # Safety
+///
+/// The alignment of the type must be no greater than 8, the alignment of a
+/// `PodChunk`'s length field, so that a `PodChunk` never contains padding.
+///
+/// [PodChunk]: struct.PodChunk.html
+pub unsafe trait PodElement: Pod {}
+
+unsafe impl PodElement for u8 {}
+unsafe impl PodElement for i8 {}
+unsafe impl PodElement for u16 {}
+unsafe impl PodElement for i16 {}
+unsafe impl PodElement for u32 {}
+unsafe impl PodElement for i32 {}
+unsafe impl PodElement for u64 {}
+unsafe impl PodElement for i64 {}
+unsafe impl PodElement for usize {}
+unsafe impl PodElement for isize {}
+unsafe impl PodElement for f32 {}
+unsafe impl PodElement for f64 {}
+
+/// A fixed capacity array of plain old data whose storage is always fully
+/// initialised.
+///
+/// Unlike a [`Chunk`][Chunk], a `PodChunk` has a stable `#[repr(C)]` layout: a
+/// `u64` length followed by `N` values of type `A`, where unused values are
+/// kept zeroed or hold whatever was last popped from them. When `N` is a
+/// multiple of 8, the whole struct is [`Pod`][Pod], so it can be cast to and
+/// from bytes for memory mapped files or shared memory.
+///
+/// Because any byte pattern is a valid `PodChunk`, a length field larger than
+/// `N` is treated as `N`.
+///
+/// This is only available with the `bytemuck` feature enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sized_chunks::PodChunk;
+/// # use typenum::U8;
+/// let mut chunk: PodChunk<u16, U8> = PodChunk::new();
+/// chunk.push(1);
+/// chunk.push(2);
+/// let bytes = bytemuck::bytes_of(&chunk);
+/// assert_eq!(8 + 8 * 2, bytes.len());
+/// let copy: PodChunk<u16, U8> = bytemuck::pod_read_unaligned(bytes);
+/// assert_eq!(&[1, 2], &*copy);
+/// ```
+///
+/// [Chunk]: struct.Chunk.html
+/// [Pod]: https://docs.rs/bytemuck/latest/bytemuck/trait.Pod.html
+#[repr(C)]
+pub struct PodChunk<A, N>
+where
+    N: ChunkLength<A>,
+{
+    length: u64,
+    data: N::SizedType,
+}
+
+impl<A, N> PodChunk<A, N>
+where
+    A: PodElement,
+    N: ChunkLength<A>,
+{
+    /// The maximum number of elements this `PodChunk` can contain.
+    pub const CAPACITY: usize = N::USIZE;
+
+    /// Construct a new empty chunk, with its storage zeroed.
+    pub fn new() -> Self {
+        Zeroable::zeroed()
+    }
+
+    /// Get the length of the chunk.
+    #[inline]
+    pub fn len(&self) -> usize {
+        if self.length > Self::CAPACITY as u64 {
+            Self::CAPACITY
+        } else {
+            self.length as usize
+        }
+    }
+
+    /// Test if the chunk is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Test if the chunk is at capacity.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() == Self::CAPACITY
+    }
+
+    /// Push an item to the back of the chunk.
+    ///
+    /// Panics if the capacity of the chunk is exceeded.
+    ///
+    /// Time: O(1)
+    pub fn push(&mut self, value: A) {
+        if self.is_full() {
+            panic!("PodChunk::push: can't push to full chunk");
+        }
+        let len = self.len();
+        self.storage_mut()[len] = value;
+        self.length = len as u64 + 1;
+    }
+
+    /// Pop an item off the back of the chunk.
+    ///
+    /// The value is left in the chunk's storage.
+    ///
+    /// Time: O(1)
+    pub fn pop(&mut self) -> Option<A> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        self.length = len as u64 - 1;
+        Some(self.storage()[len - 1])
+    }
+
+    /// Discard the contents of the chunk, zeroing its storage.
+    ///
+    /// Time: O(n)
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    fn storage(&self) -> &[A] {
+        unsafe { from_raw_parts(&self.data as *const _ as *const A, Self::CAPACITY) }
+    }
+
+    fn storage_mut(&mut self) -> &mut [A] {
+        unsafe { from_raw_parts_mut(&mut self.data as *mut _ as *mut A, Self::CAPACITY) }
+    }
+}
+
+impl<A, N> Clone for PodChunk<A, N>
+where
+    A: Copy,
+    N: ChunkLength<A>,
+    N::SizedType: Copy,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A, N> Copy for PodChunk<A, N>
+where
+    A: Copy,
+    N: ChunkLength<A>,
+    N::SizedType: Copy,
+{
+}
+
+// A zeroed `N::SizedType` is `N` zeroed values of `A`, which is valid for
+// `A: Zeroable`.
+unsafe impl<A, N> Zeroable for PodChunk<A, N>
+where
+    A: Zeroable,
+    N: ChunkLength<A>,
+{
+}
+
+// `length` is 8 bytes, and `A: PodElement` means `data` has an alignment of at
+// most 8, so it follows `length` without padding. `N` being a multiple of 8
+// makes the size of `data` a multiple of 8, so there's no trailing padding
+// either.
+unsafe impl<A, N> Pod for PodChunk<A, N>
+where
+    A: PodElement,
+    N: ChunkLength<A> + Rem<U8, Output = U0> + 'static,
+    N::SizedType: Copy + 'static,
+{
+}
+
+impl<A, N> Default for PodChunk<A, N>
+where
+    A: PodElement,
+    N: ChunkLength<A>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A, N> Deref for PodChunk<A, N>
+where
+    A: PodElement,
+    N: ChunkLength<A>,
+{
+    type Target = [A];
+
+    fn deref(&self) -> &Self::Target {
+        let len = self.len();
+        &self.storage()[..len]
+    }
+}
+
+impl<A, N> DerefMut for PodChunk<A, N>
+where
+    A: PodElement,
+    N: ChunkLength<A>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        let len = self.len();
+        &mut self.storage_mut()[..len]
+    }
+}
+
+impl<A, N> Debug for PodChunk<A, N>
+where
+    A: PodElement + Debug,
+    N: ChunkLength<A>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("PodChunk")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<A, N> From<Chunk<A, N>> for PodChunk<A, N>
+where
+    A: PodElement,
+    N: ChunkLength<A>,
+{
+    fn from(chunk: Chunk<A, N>) -> Self {
+        let mut out = Self::new();
+        out.storage_mut()[..chunk.len()].copy_from_slice(&chunk);
+        out.length = chunk.len() as u64;
+        out
+    }
+}
+
+impl<A, N> From<PodChunk<A, N>> for Chunk<A, N>
+where
+    A: PodElement,
+    N: ChunkLength<A>,
+{
+    fn from(chunk: PodChunk<A, N>) -> Self {
+        Chunk::from_iter(chunk.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::mem;
+    use typenum::{U3, U8};
+
+    #[test]
+    fn push_and_pop() {
+        let mut chunk: PodChunk<u32, U3> = PodChunk::new();
+        chunk.push(1);
+        chunk.push(2);
+        chunk.push(3);
+        assert!(chunk.is_full());
+        assert_eq!(Some(3), chunk.pop());
+        assert_eq!(&[1, 2], &*chunk);
+        let chunk: Chunk<u32, U3> = chunk.into();
+        assert_eq!(&[1, 2], chunk.as_slice());
+        assert_eq!(&[1, 2], &*PodChunk::from(chunk));
+    }
+
+    #[test]
+    fn pod_layout() {
+        type Bytes = PodChunk<u8, U8>;
+        assert_eq!(16, mem::size_of::<Bytes>());
+        let mut chunk = Bytes::new();
+        for value in 1..=3 {
+            chunk.push(value);
+        }
+        let mut bytes = bytemuck::bytes_of(&chunk).to_vec();
+        assert_eq!(3u64.to_ne_bytes(), bytes[..8]);
+        assert_eq!(&[1, 2, 3, 0, 0, 0, 0, 0], &bytes[8..]);
+        // An out of range length is clamped to the capacity.
+        bytes[..8].copy_from_slice(&u64::MAX.to_ne_bytes());
+        let chunk: Bytes = bytemuck::pod_read_unaligned(&bytes);
+        assert_eq!(8, chunk.len());
+        assert!(chunk.is_full());
+    }
+}
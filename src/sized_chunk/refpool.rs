@@ -10,11 +10,7 @@ where
     N: ChunkLength<A>,
 {
     unsafe fn default_uninit(target: &mut MaybeUninit<Self>) {
-        let ptr = target.as_mut_ptr();
-        let left_ptr: *mut usize = &mut (*ptr).left;
-        let right_ptr: *mut usize = &mut (*ptr).right;
-        left_ptr.write(0);
-        right_ptr.write(0);
+        Chunk::init_in_place(target);
     }
 }
 
@@ -0,0 +1,442 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use std::fmt::{Debug, Error, Formatter};
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+use std::slice::{from_raw_parts, from_raw_parts_mut};
+
+/// The bookkeeping for a [`ChunkView`][ChunkView], kept separately from the
+/// view so it can live alongside the storage it describes.
+///
+/// [ChunkView]: struct.ChunkView.html
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct ChunkViewState {
+    left: usize,
+    right: usize,
+}
+
+impl ChunkViewState {
+    /// Construct the state of an empty chunk.
+    pub const fn new() -> Self {
+        ChunkViewState { left: 0, right: 0 }
+    }
+
+    /// Get the number of values in the chunk this state describes.
+    pub fn len(&self) -> usize {
+        self.right - self.left
+    }
+
+    /// Test if the chunk this state describes is empty.
+    pub fn is_empty(&self) -> bool {
+        self.left == self.right
+    }
+}
+
+/// A [`Chunk`][Chunk] stored in memory owned by someone else.
+///
+/// A `ChunkView` works like a `Chunk` whose capacity is the length of the
+/// `&mut [MaybeUninit<A>]` slice it's given, and whose bookkeeping is kept in
+/// a separate [`ChunkViewState`][ChunkViewState], so you can keep a chunk in a
+/// slot of an arena or a memory mapped file and come back to it later. It
+/// shares its implementation with `Chunk`, and behaves the same way.
+///
+/// Because the storage and its state outlive the view, dropping a view leaves
+/// the values in it alone. Use [`clear`][clear] to drop them.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::mem::MaybeUninit;
+/// # use sized_chunks::sized_chunk::{ChunkView, ChunkViewState};
+/// let mut storage: [MaybeUninit<i32>; 8] = [MaybeUninit::uninit(); 8];
+/// let mut state = ChunkViewState::new();
+/// {
+///     let mut view = ChunkView::new(&mut state, &mut storage);
+///     view.push_back(2);
+///     view.push_front(1);
+///     view.insert_ordered(3);
+/// }
+/// assert_eq!(3, state.len());
+///
+/// let mut view = unsafe { ChunkView::from_parts(&mut state, &mut storage) };
+/// assert_eq!(&[1, 2, 3], view.as_slice());
+/// view.clear();
+/// ```
+///
+/// [Chunk]: struct.Chunk.html
+/// [ChunkViewState]: struct.ChunkViewState.html
+/// [clear]: #method.clear
+pub struct ChunkView<'a, A> {
+    left: &'a mut usize,
+    right: &'a mut usize,
+    data: &'a mut [MaybeUninit<A>],
+}
+
+impl<'a, A> ChunkView<'a, A> {
+    /// Construct an empty chunk in `data`, resetting `state`.
+    ///
+    /// Any values `state` said were in `data` are forgotten, not dropped.
+    pub fn new(state: &'a mut ChunkViewState, data: &'a mut [MaybeUninit<A>]) -> Self {
+        *state = ChunkViewState::new();
+        ChunkView {
+            left: &mut state.left,
+            right: &mut state.right,
+            data,
+        }
+    }
+
+    /// Construct a view of a chunk previously stored in `data` with `state`.
+    ///
+    /// # Safety
+    ///
+    /// `state` must describe a range within `data`, and every value in that
+    /// range must be initialised, as they will be if `state` and `data` were
+    /// last used together by a `ChunkView`.
+    pub unsafe fn from_parts(
+        state: &'a mut ChunkViewState,
+        data: &'a mut [MaybeUninit<A>],
+    ) -> Self {
+        check!(
+            state.left <= state.right && state.right <= data.len(),
+            "ChunkView::from_parts: state out of bounds for storage"
+        );
+        ChunkView {
+            left: &mut state.left,
+            right: &mut state.right,
+            data,
+        }
+    }
+
+    /// Construct a view from its raw parts, as used by `Chunk`.
+    ///
+    /// # Safety
+    ///
+    /// `left..right` must be a range within `data`, and every value in that
+    /// range must be initialised.
+    pub(crate) unsafe fn from_raw(
+        left: &'a mut usize,
+        right: &'a mut usize,
+        data: &'a mut [MaybeUninit<A>],
+    ) -> Self {
+        ChunkView { left, right, data }
+    }
+
+    #[inline]
+    fn ptr(&self, index: usize) -> *const A {
+        self.data.as_ptr().wrapping_add(index).cast()
+    }
+
+    #[inline]
+    fn mut_ptr(&mut self, index: usize) -> *mut A {
+        self.data.as_mut_ptr().wrapping_add(index).cast()
+    }
+
+    /// Copy a range within the chunk.
+    #[inline]
+    unsafe fn force_copy(&mut self, from: usize, to: usize, count: usize) {
+        if count > 0 {
+            ptr::copy(self.ptr(from), self.mut_ptr(to), count)
+        }
+    }
+
+    /// Write a value at an index without trying to drop what's already there.
+    #[inline]
+    unsafe fn force_write(&mut self, index: usize, value: A) {
+        ptr::write(self.mut_ptr(index), value)
+    }
+
+    /// Copy the value at an index, discarding ownership of the copied value.
+    #[inline]
+    unsafe fn force_read(&self, index: usize) -> A {
+        ptr::read(self.ptr(index))
+    }
+
+    /// Get the maximum number of values the chunk can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Get the number of values in the chunk.
+    #[inline]
+    pub fn len(&self) -> usize {
+        *self.right - *self.left
+    }
+
+    /// Test if the chunk is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        *self.left == *self.right
+    }
+
+    /// Test if the chunk is at capacity.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Get the number of free slots at the front of the chunk.
+    pub fn left_capacity(&self) -> usize {
+        *self.left
+    }
+
+    /// Get the number of free slots at the back of the chunk.
+    pub fn right_capacity(&self) -> usize {
+        self.capacity() - *self.right
+    }
+
+    /// Push a value to the front of the chunk.
+    ///
+    /// Panics if the chunk is full.
+    ///
+    /// Time: O(1) if there's room at the front, O(n) otherwise
+    pub fn push_front(&mut self, value: A) {
+        if self.is_full() {
+            panic!("ChunkView::push_front: can't push to full chunk");
+        }
+        let capacity = self.capacity();
+        if self.is_empty() {
+            *self.left = capacity;
+            *self.right = capacity;
+        } else if *self.left == 0 {
+            let left = capacity - *self.right;
+            unsafe { self.force_copy(0, left, *self.right) };
+            *self.left = left;
+            *self.right = capacity;
+        }
+        *self.left -= 1;
+        unsafe { self.force_write(*self.left, value) }
+    }
+
+    /// Push a value to the back of the chunk.
+    ///
+    /// Panics if the chunk is full.
+    ///
+    /// Time: O(1) if there's room at the back, O(n) otherwise
+    pub fn push_back(&mut self, value: A) {
+        if self.is_full() {
+            panic!("ChunkView::push_back: can't push to full chunk");
+        }
+        if self.is_empty() {
+            *self.left = 0;
+            *self.right = 0;
+        } else if *self.right == self.capacity() {
+            let len = self.len();
+            unsafe { self.force_copy(*self.left, 0, len) };
+            *self.right = len;
+            *self.left = 0;
+        }
+        unsafe { self.force_write(*self.right, value) }
+        *self.right += 1;
+    }
+
+    /// Pop a value off the front of the chunk.
+    ///
+    /// Panics if the chunk is empty.
+    ///
+    /// Time: O(1)
+    pub fn pop_front(&mut self) -> A {
+        if self.is_empty() {
+            panic!("ChunkView::pop_front: can't pop from empty chunk");
+        }
+        let value = unsafe { self.force_read(*self.left) };
+        *self.left += 1;
+        value
+    }
+
+    /// Pop a value off the back of the chunk.
+    ///
+    /// Panics if the chunk is empty.
+    ///
+    /// Time: O(1)
+    pub fn pop_back(&mut self) -> A {
+        if self.is_empty() {
+            panic!("ChunkView::pop_back: can't pop from empty chunk");
+        }
+        *self.right -= 1;
+        unsafe { self.force_read(*self.right) }
+    }
+
+    /// Insert a value at index `index`, shifting the values on whichever side
+    /// of it is cheaper to move.
+    ///
+    /// Panics if the index is out of bounds or the chunk is full.
+    ///
+    /// Time: O(n) for the number of values shifted
+    pub fn insert(&mut self, index: usize, value: A) {
+        if self.is_full() {
+            panic!("ChunkView::insert: chunk is full");
+        }
+        if index > self.len() {
+            panic!("ChunkView::insert: index out of bounds");
+        }
+        let left = *self.left;
+        let real_index = index + left;
+        let left_size = index;
+        let right_size = *self.right - real_index;
+        if *self.right == self.capacity() || (left > 0 && left_size < right_size) {
+            unsafe {
+                self.force_copy(left, left - 1, left_size);
+                self.force_write(real_index - 1, value);
+            }
+            *self.left -= 1;
+        } else {
+            unsafe {
+                self.force_copy(real_index, real_index + 1, right_size);
+                self.force_write(real_index, value);
+            }
+            *self.right += 1;
+        }
+    }
+
+    /// Insert a value into the chunk in sorted order.
+    ///
+    /// This assumes every value in the chunk is already in sorted order.
+    ///
+    /// Panics if the chunk is full.
+    ///
+    /// Time: O(log n) to find the insert position, then O(n) for the number
+    /// of values shifted
+    pub fn insert_ordered(&mut self, value: A)
+    where
+        A: Ord,
+    {
+        if self.is_full() {
+            panic!("ChunkView::insert: chunk is full");
+        }
+        match self.binary_search(&value) {
+            Ok(index) | Err(index) => self.insert(index, value),
+        }
+    }
+
+    /// Remove the value at index `index`, shifting the values on whichever
+    /// side of it is cheaper to move.
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// Time: O(n) for the number of values shifted
+    pub fn remove(&mut self, index: usize) -> A {
+        if index >= self.len() {
+            panic!("ChunkView::remove: index out of bounds");
+        }
+        let left = *self.left;
+        let real_index = index + left;
+        let value = unsafe { self.force_read(real_index) };
+        let left_size = index;
+        let right_size = *self.right - real_index - 1;
+        if left_size < right_size {
+            unsafe { self.force_copy(left, left + 1, left_size) };
+            *self.left += 1;
+        } else {
+            unsafe { self.force_copy(real_index + 1, real_index, right_size) };
+            *self.right -= 1;
+        }
+        value
+    }
+
+    /// Drop every value in the chunk.
+    ///
+    /// Time: O(n)
+    pub fn clear(&mut self) {
+        let dropped: *mut [A] = self.as_mut_slice();
+        *self.left = 0;
+        *self.right = 0;
+        unsafe { ptr::drop_in_place(dropped) }
+    }
+
+    /// Get a reference to the contents of the chunk as a slice.
+    pub fn as_slice(&self) -> &[A] {
+        unsafe { from_raw_parts(self.ptr(*self.left), self.len()) }
+    }
+
+    /// Get a reference to the contents of the chunk as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [A] {
+        let len = self.len();
+        unsafe { from_raw_parts_mut(self.mut_ptr(*self.left), len) }
+    }
+}
+
+impl<'a, A> Deref for ChunkView<'a, A> {
+    type Target = [A];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<'a, A> DerefMut for ChunkView<'a, A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<'a, A> Debug for ChunkView<'a, A>
+where
+    A: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.write_str("ChunkView")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::tests::DropTest;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn push_pop_insert_remove() {
+        let mut storage: Vec<MaybeUninit<i32>> = (0..6).map(|_| MaybeUninit::uninit()).collect();
+        let mut state = ChunkViewState::new();
+        let mut view = ChunkView::new(&mut state, &mut storage);
+        view.push_back(3);
+        view.push_front(1);
+        view.insert(1, 2);
+        view.insert_ordered(0);
+        view.push_back(5);
+        view.insert(4, 4);
+        assert!(view.is_full());
+        assert_eq!(&[0, 1, 2, 3, 4, 5], view.as_slice());
+        assert_eq!(2, view.remove(2));
+        assert_eq!(0, view.pop_front());
+        assert_eq!(5, view.pop_back());
+        assert_eq!(3, state.len());
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "checked"))]
+    #[should_panic(expected = "ChunkView::from_parts: state out of bounds for storage")]
+    fn from_parts_out_of_bounds() {
+        let mut storage: [MaybeUninit<i32>; 2] = [MaybeUninit::uninit(); 2];
+        let mut state = ChunkViewState { left: 0, right: 3 };
+        let _ = unsafe { ChunkView::from_parts(&mut state, &mut storage) };
+    }
+
+    #[test]
+    fn values_outlive_view() {
+        let counter = AtomicUsize::new(0);
+        let mut storage: [MaybeUninit<DropTest<'_>>; 4] = [
+            MaybeUninit::uninit(),
+            MaybeUninit::uninit(),
+            MaybeUninit::uninit(),
+            MaybeUninit::uninit(),
+        ];
+        let mut state = ChunkViewState::new();
+        {
+            let mut view = ChunkView::new(&mut state, &mut storage);
+            view.push_back(DropTest::new(&counter));
+            view.push_front(DropTest::new(&counter));
+        }
+        assert_eq!(2, counter.load(Ordering::Relaxed));
+        let mut view = unsafe { ChunkView::from_parts(&mut state, &mut storage) };
+        assert_eq!(2, view.len());
+        view.clear();
+        assert_eq!(0, counter.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,54 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use ::zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::types::ChunkLength;
+use crate::Chunk;
+
+impl<A, N> Zeroize for Chunk<A, N>
+where
+    N: ChunkLength<A>,
+{
+    /// Drop the contents of the chunk and overwrite its entire backing store,
+    /// including any unused space on either side of the contents, with zeroes.
+    fn zeroize(&mut self) {
+        self.clear();
+        crate::types::zeroize_storage(&mut self.data);
+    }
+}
+
+impl<A, N> ZeroizeOnDrop for Chunk<A, N> where N: ChunkLength<A> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::mem::ManuallyDrop;
+    use std::ptr;
+    use typenum::U32;
+
+    fn storage<N: ChunkLength<u8>>(chunk: &Chunk<u8, N>) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(&chunk.data as *const _ as *const u8, N::USIZE) }
+    }
+
+    #[test]
+    fn zeroize_wipes_full_capacity() {
+        let mut chunk: Chunk<u8, U32> = (1..=32).collect();
+        chunk.drop_left(8);
+        chunk.pop_back();
+        chunk.zeroize();
+        assert!(chunk.is_empty());
+        assert!(storage(&chunk).iter().all(|byte| *byte == 0));
+        chunk.push_back(1);
+        assert_eq!(&[1], chunk.as_slice());
+    }
+
+    #[test]
+    fn drop_wipes_full_capacity() {
+        let mut chunk: ManuallyDrop<Chunk<u8, U32>> = ManuallyDrop::new((1..=32).collect());
+        chunk.drop_right(16);
+        unsafe { ptr::drop_in_place(&mut *chunk) };
+        assert!(storage(&chunk).iter().all(|byte| *byte == 0));
+    }
+}
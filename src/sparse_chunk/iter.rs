@@ -1,11 +1,12 @@
-use bitmaps::{Bitmap, Bits, Iter as BitmapIter};
+use bitmaps::{Bitmap, Bits};
 
 use super::SparseChunk;
+use crate::sparse_set::Iter as Indices;
 use crate::types::ChunkLength;
 
 /// An iterator over references to the elements of a `SparseChunk`.
 pub struct Iter<'a, A, N: Bits + ChunkLength<A>> {
-    pub(crate) indices: BitmapIter<'a, N>,
+    pub(crate) indices: Indices<N>,
     pub(crate) chunk: &'a SparseChunk<A, N>,
 }
 
@@ -17,7 +18,7 @@ impl<'a, A, N: Bits + ChunkLength<A>> Iterator for Iter<'a, A, N> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(SparseChunk::<A, N>::CAPACITY))
+        self.indices.size_hint()
     }
 }
 
@@ -55,7 +56,7 @@ pub struct Drain<A, N: Bits + ChunkLength<A>> {
     pub(crate) chunk: SparseChunk<A, N>,
 }
 
-impl<'a, A, N: Bits + ChunkLength<A>> Iterator for Drain<A, N> {
+impl<A, N: Bits + ChunkLength<A>> Iterator for Drain<A, N> {
     type Item = A;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -144,7 +145,7 @@ pub struct OptionDrain<A, N: Bits + ChunkLength<A>> {
     pub(crate) chunk: SparseChunk<A, N>,
 }
 
-impl<'a, A, N: Bits + ChunkLength<A>> Iterator for OptionDrain<A, N> {
+impl<A, N: Bits + ChunkLength<A>> Iterator for OptionDrain<A, N> {
     type Item = Option<A>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -175,21 +176,21 @@ mod test {
         #[test]
         fn iter(ref vec in vec(of(usize::ANY), 0..64)) {
             let chunk: SparseChunk<_, U64> = vec.iter().cloned().collect();
-            let vec: Vec<usize> = vec.iter().cloned().filter(|v| v.is_some()).map(|v| v.unwrap()).collect();
+            let vec: Vec<usize> = vec.iter().cloned().flatten().collect();
             prop_assert!(vec.iter().eq(chunk.iter()));
         }
 
         #[test]
         fn iter_mut(ref vec in vec(of(usize::ANY), 0..64)) {
             let mut chunk: SparseChunk<_, U64> = vec.iter().cloned().collect();
-            let mut vec: Vec<usize> = vec.iter().cloned().filter(|v| v.is_some()).map(|v| v.unwrap()).collect();
+            let mut vec: Vec<usize> = vec.iter().cloned().flatten().collect();
             prop_assert!(vec.iter_mut().eq(chunk.iter_mut()));
         }
 
         #[test]
         fn drain(ref vec in vec(of(usize::ANY), 0..64)) {
             let chunk: SparseChunk<_, U64> = vec.iter().cloned().collect();
-            let vec: Vec<usize> = vec.iter().cloned().filter(|v| v.is_some()).map(|v| v.unwrap()).collect();
+            let vec: Vec<usize> = vec.iter().cloned().flatten().collect();
             prop_assert!(vec.into_iter().eq(chunk.into_iter()));
         }
 
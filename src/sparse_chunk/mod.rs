@@ -12,13 +12,15 @@ use std::iter::FromIterator;
 use std::mem::{self, MaybeUninit};
 use std::ops::Index;
 use std::ops::IndexMut;
+use std::ops::{Add, Sub};
 use std::ptr;
 use std::slice::{from_raw_parts, from_raw_parts_mut};
 
-use typenum::U64;
+use typenum::{Diff, Sum, U64};
 
 use bitmaps::{Bitmap, Bits, Iter as BitmapIter};
 
+use crate::sparse_set::Iter as Indices;
 use crate::types::ChunkLength;
 
 mod iter;
@@ -27,6 +29,9 @@ pub use self::iter::{Drain, Iter, IterMut, OptionDrain, OptionIter, OptionIterMu
 #[cfg(feature = "refpool")]
 mod refpool;
 
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
 /// A fixed capacity sparse array.
 ///
 /// An inline sparse array of up to `N` items of type `A`, where `N` is an
@@ -34,12 +39,17 @@ mod refpool;
 /// of `Option<A>`, where the discriminant (whether the value is `Some<A>` or
 /// `None`) is kept in a bitmap instead of adjacent to the value.
 ///
-/// Because the bitmap is kept in a primitive type, the maximum value of `N` is
-/// currently 128, corresponding to a type of `u128`. The type of the bitmap
-/// will be the minimum unsigned integer type required to fit the number of bits
-/// required. Thus, disregarding memory alignment rules, the allocated size of a
-/// `SparseChunk` will be `uX` + `A` * `N` where `uX` is the type of the
-/// discriminant bitmap, either `u8`, `u16`, `u32`, `u64` or `u128`.
+/// Up to a size of 128, the bitmap is kept in a primitive type: the minimum
+/// unsigned integer type required to fit the number of bits required. Beyond
+/// that, it's kept in an array of `u128` words, up to a maximum `N` of 1024,
+/// corresponding to a type of `[u128; 8]`. Thus, disregarding memory alignment
+/// rules, the allocated size of a `SparseChunk` will be `uX` + `A` * `N` where
+/// `uX` is the type of the discriminant bitmap, either `u8`, `u16`, `u32`,
+/// `u64`, `u128` or `[u128; (N + 127) / 128]`.
+///
+/// Iterating over a `SparseChunk` scans its bitmap a word at a time, skipping
+/// over empty words, so a large, mostly empty chunk is still cheap to iterate
+/// over.
 ///
 /// # Examples
 ///
@@ -70,18 +80,19 @@ pub struct SparseChunk<A, N: Bits + ChunkLength<A> = U64> {
 impl<A, N: Bits + ChunkLength<A>> Drop for SparseChunk<A, N> {
     fn drop(&mut self) {
         if mem::needs_drop::<A>() {
-            let bits = self.map;
-            for index in &bits {
+            for index in self.index_iter() {
                 unsafe { ptr::drop_in_place(&mut self.values_mut()[index]) }
             }
         }
+        #[cfg(feature = "zeroize")]
+        crate::types::zeroize_storage(&mut self.data);
     }
 }
 
 impl<A: Clone, N: Bits + ChunkLength<A>> Clone for SparseChunk<A, N> {
     fn clone(&self) -> Self {
         let mut out = Self::new();
-        for index in &self.map {
+        for index in self.index_iter() {
             out.insert(index, self[index].clone());
         }
         out
@@ -108,13 +119,13 @@ where
     /// Copy the value at an index, discarding ownership of the copied value
     #[inline]
     unsafe fn force_read(index: usize, chunk: &Self) -> A {
-        ptr::read(&chunk.values()[index as usize])
+        ptr::read(&chunk.values()[index])
     }
 
     /// Write a value at an index without trying to drop what's already there
     #[inline]
     unsafe fn force_write(index: usize, value: A, chunk: &mut Self) {
-        ptr::write(&mut chunk.values_mut()[index as usize], value)
+        ptr::write(&mut chunk.values_mut()[index], value)
     }
 
     /// Construct a new empty chunk.
@@ -125,6 +136,61 @@ where
         }
     }
 
+    /// Construct a new empty chunk in place.
+    ///
+    /// This only initialises the chunk's bookkeeping, leaving its storage
+    /// untouched, so it's cheap however large the chunk is, and the chunk
+    /// never needs to exist on the stack.
+    pub fn init_in_place(target: &mut MaybeUninit<Self>) -> &mut Self {
+        unsafe {
+            let ptr = target.as_mut_ptr();
+            ptr::addr_of_mut!((*ptr).map).write(Bitmap::new());
+            &mut *ptr
+        }
+    }
+
+    /// Construct a new empty chunk directly on the heap.
+    ///
+    /// Unlike `Box::new(SparseChunk::new())`, this never builds the chunk on the
+    /// stack first, so it's safe to use for chunks too large to fit there.
+    pub fn new_boxed() -> Box<Self> {
+        let mut boxed = crate::boxed::new_uninit::<Self>();
+        Self::init_in_place(&mut boxed);
+        unsafe { crate::boxed::assume_init(boxed) }
+    }
+
+    /// Clone the chunk directly onto the heap.
+    ///
+    /// See [`new_boxed`](#method.new_boxed).
+    pub fn clone_boxed(&self) -> Box<Self>
+    where
+        A: Clone,
+    {
+        let mut out = Self::new_boxed();
+        for index in self.index_iter() {
+            out.insert(index, self[index].clone());
+        }
+        out
+    }
+
+    /// Construct a chunk directly on the heap from an iterator of `Option`s, as with `FromIterator`.
+    ///
+    /// See [`new_boxed`](#method.new_boxed).
+    ///
+    /// Panics if the iterator yields more values than the chunk can hold.
+    pub fn collect_boxed<I>(iter: I) -> Box<Self>
+    where
+        I: IntoIterator<Item = Option<A>>,
+    {
+        let mut out = Self::new_boxed();
+        for (index, value) in iter.into_iter().enumerate() {
+            if let Some(value) = value {
+                out.insert(index, value);
+            }
+        }
+        out
+    }
+
     /// Construct a new chunk with one item.
     pub fn unit(index: usize, value: A) -> Self {
         let mut chunk = Self::new();
@@ -194,6 +260,84 @@ where
         self.first_index().and_then(|index| self.remove(index))
     }
 
+    /// Move every value at index `start` or above into a chunk of another
+    /// size, at its index minus `start` plus `offset`.
+    fn move_into<M>(&mut self, target: &mut SparseChunk<A, M>, start: usize, offset: usize)
+    where
+        M: Bits + ChunkLength<A>,
+    {
+        for index in self.index_iter().filter(|index| *index >= start) {
+            let value = unsafe { SparseChunk::force_read(index, self) };
+            self.map.set(index, false);
+            unsafe { SparseChunk::force_write(index - start + offset, value, target) };
+            target.map.set(index - start + offset, true);
+        }
+    }
+
+    /// Concatenate two chunks into a chunk big enough to hold both of them,
+    /// with the values of `other` placed after the `N` indices of `self`.
+    ///
+    /// Time: O(n) for the number of items moved
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use sized_chunks::SparseChunk;
+    /// # use typenum::{U3, U4, U7};
+    /// let left = SparseChunk::<i32, U3>::pair(0, 0, 2, 2);
+    /// let right = SparseChunk::<i32, U4>::unit(1, 4);
+    /// let chunk: SparseChunk<i32, U7> = left.concat(right);
+    /// assert_eq!(vec![0, 2, 4], chunk.indices().collect::<Vec<_>>());
+    /// ```
+    pub fn concat<M>(mut self, mut other: SparseChunk<A, M>) -> SparseChunk<A, Sum<N, M>>
+    where
+        M: Bits + ChunkLength<A>,
+        N: Add<M>,
+        Sum<N, M>: Bits + ChunkLength<A>,
+    {
+        let mut out = SparseChunk::new();
+        self.move_into(&mut out, 0, 0);
+        other.move_into(&mut out, 0, N::USIZE);
+        out
+    }
+
+    /// Split a chunk into a chunk of capacity `K` holding the values at
+    /// indices below `K`, and a chunk holding the rest, with `K` subtracted
+    /// from their indices.
+    ///
+    /// Time: O(n) for the number of items moved
+    pub fn split_at_type<K>(mut self) -> (SparseChunk<A, K>, SparseChunk<A, Diff<N, K>>)
+    where
+        K: Bits + ChunkLength<A>,
+        N: Sub<K>,
+        Diff<N, K>: Bits + ChunkLength<A>,
+    {
+        let mut left = SparseChunk::new();
+        let mut right = SparseChunk::new();
+        self.move_into(&mut right, K::USIZE, 0);
+        self.move_into(&mut left, 0, 0);
+        (left, right)
+    }
+
+    /// Move the contents of the chunk into a chunk of capacity `M`, keeping
+    /// every value at the same index.
+    ///
+    /// Returns the chunk unchanged as an error if it has a value at an index
+    /// of `M` or above.
+    ///
+    /// Time: O(n) for the number of items moved
+    pub fn resize_capacity<M>(mut self) -> Result<SparseChunk<A, M>, Self>
+    where
+        M: Bits + ChunkLength<A>,
+    {
+        if self.index_iter().any(|index| index >= M::USIZE) {
+            return Err(self);
+        }
+        let mut out = SparseChunk::new();
+        self.move_into(&mut out, 0, 0);
+        Ok(out)
+    }
+
     /// Get the value at a given index.
     pub fn get(&self, index: usize) -> Option<&A> {
         if index >= N::USIZE {
@@ -243,6 +387,18 @@ where
         self.map.into_iter()
     }
 
+    /// Make an iterator over the indices which contain values, scanning the
+    /// bitmap a word at a time.
+    pub(crate) fn index_iter(&self) -> Indices<N> {
+        Indices::new(self.map)
+    }
+
+    /// Get a copy of the bitmap of indices which contain values.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn bitmap(&self) -> Bitmap<N> {
+        self.map
+    }
+
     /// Find the first index which contains a value.
     pub fn first_index(&self) -> Option<usize> {
         self.map.first_index()
@@ -251,7 +407,7 @@ where
     /// Make an iterator of references to the values contained in the array.
     pub fn iter(&self) -> Iter<'_, A, N> {
         Iter {
-            indices: self.indices(),
+            indices: self.index_iter(),
             chunk: self,
         }
     }
@@ -273,7 +429,7 @@ where
     /// Make an iterator of pairs of indices and references to the values
     /// contained in the array.
     pub fn entries(&self) -> impl Iterator<Item = (usize, &A)> {
-        self.indices().zip(self.iter())
+        self.index_iter().zip(self.iter())
     }
 
     /// Make an iterator of `Option`s of references to the values contained in the array.
@@ -366,7 +522,7 @@ where
         if self.map != other.map {
             return false;
         }
-        for index in self.indices() {
+        for index in self.index_iter() {
             if self.get(index) != other.get(index) {
                 return false;
             }
@@ -384,7 +540,7 @@ where
         if self.len() != other.len() {
             return false;
         }
-        for index in self.indices() {
+        for index in self.index_iter() {
             if self.get(index) != other.get(&index) {
                 return false;
             }
@@ -402,7 +558,7 @@ where
         if self.len() != other.len() {
             return false;
         }
-        for index in self.indices() {
+        for index in self.index_iter() {
             if self.get(index) != other.get(&index) {
                 return false;
             }
@@ -432,7 +588,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use typenum::U32;
+    use typenum::{U1024, U32};
 
     #[test]
     fn insert_remove_iterate() {
@@ -469,9 +625,38 @@ mod test {
         assert_eq!(vec![1, 5, 22, 24], right_indices);
     }
 
-    use crate::tests::DropTest;
+    use crate::tests::{assert_panics, DropTest, PanicOnClone};
     use std::sync::atomic::{AtomicUsize, Ordering};
 
+    #[test]
+    fn type_level_resizing() {
+        use typenum::{U3, U5, U8};
+        let chunk = SparseChunk::<i32, U5>::pair(1, 1, 4, 4);
+        let (left, right) = chunk.split_at_type::<U3>();
+        assert_eq!(vec![1], left.indices().collect::<Vec<_>>());
+        assert_eq!(vec![1], right.indices().collect::<Vec<_>>());
+        assert_eq!(Some(&4), right.get(1));
+        let chunk: SparseChunk<i32, U5> = right.concat(left);
+        assert_eq!(vec![1, 3], chunk.indices().collect::<Vec<_>>());
+        assert_eq!(Some(&1), chunk.get(3));
+        let chunk = chunk.resize_capacity::<U3>().unwrap_err();
+        let chunk = chunk.resize_capacity::<U8>().unwrap();
+        assert_eq!(vec![&4, &1], chunk.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn clone_panic_safety() {
+        let counter = AtomicUsize::new(0);
+        {
+            let mut chunk: SparseChunk<PanicOnClone<'_>, U32> = SparseChunk::new();
+            chunk.insert(3, PanicOnClone::new(&counter));
+            chunk.insert(9, PanicOnClone::panicking(&counter));
+            assert_panics(|| drop(chunk.clone()));
+            assert_eq!(2, counter.load(Ordering::Relaxed));
+        }
+        assert_eq!(0, counter.load(Ordering::Relaxed));
+    }
+
     #[test]
     fn dropping() {
         let counter = AtomicUsize::new(0);
@@ -507,4 +692,40 @@ mod test {
         }
         assert_ne!(c1, c2);
     }
+
+    #[test]
+    fn multi_word_bitmap() {
+        let mut chunk: SparseChunk<u16, U1024> = SparseChunk::new();
+        for index in &[1023, 0, 300, 127, 128, 640] {
+            chunk.insert(*index, *index as u16);
+        }
+        assert_eq!(6, chunk.len());
+        assert_eq!(Some(0), chunk.first_index());
+        assert_eq!(Some(0), chunk.pop());
+        assert_eq!(Some(127), chunk.first_index());
+        let indices: Vec<_> = chunk.indices().collect();
+        assert_eq!(vec![127, 128, 300, 640, 1023], indices);
+        assert_eq!(indices, chunk.index_iter().collect::<Vec<_>>());
+        let values: Vec<_> = chunk.iter().cloned().collect();
+        assert_eq!(vec![127, 128, 300, 640, 1023], values);
+        let cloned = chunk.clone();
+        assert_eq!(chunk, cloned);
+        assert_eq!(Some(300), chunk.remove(300));
+        assert_eq!(Some(&640), chunk.get(640));
+        assert_eq!(None, chunk.get(300));
+    }
+
+    #[test]
+    fn boxed_chunks() {
+        // A 128k chunk on a 64k stack would overflow if it ever touched it.
+        crate::tests::on_small_stack(|| {
+            type Chunk = SparseChunk<[u64; 16], U1024>;
+            let chunk = Chunk::collect_boxed(vec![None, Some([1; 16]), None, Some([3; 16])]);
+            // `bitmaps`' own iterator recurses for each empty index, which
+            // overflows this stack in a debug build, so use ours.
+            assert_eq!(vec![1, 3], chunk.index_iter().collect::<Vec<_>>());
+            assert_eq!(*chunk, *chunk.clone_boxed());
+            assert!(Chunk::new_boxed().is_empty());
+        });
+    }
 }
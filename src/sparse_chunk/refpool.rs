@@ -12,9 +12,7 @@ where
     N: Bits + ChunkLength<A>,
 {
     unsafe fn default_uninit(target: &mut MaybeUninit<Self>) {
-        let ptr = target.as_mut_ptr();
-        let map_ptr: *mut Bitmap<N> = &mut (*ptr).map;
-        map_ptr.write(Bitmap::new());
+        SparseChunk::init_in_place(target);
     }
 }
 
@@ -29,7 +27,7 @@ where
         let data_ptr: *mut _ = &mut (*ptr).data;
         let data_ptr: *mut A = (*data_ptr).as_mut_ptr().cast();
         map_ptr.write(self.map);
-        for index in &self.map {
+        for index in self.index_iter() {
             data_ptr.add(index).write(self[index].clone());
         }
     }
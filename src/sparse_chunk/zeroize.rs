@@ -0,0 +1,45 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+use ::zeroize::{Zeroize, ZeroizeOnDrop};
+use bitmaps::Bits;
+
+use crate::types::ChunkLength;
+use crate::SparseChunk;
+
+impl<A, N> Zeroize for SparseChunk<A, N>
+where
+    N: Bits + ChunkLength<A>,
+{
+    /// Drop the contents of the chunk and overwrite its entire backing store,
+    /// including unoccupied indices, with zeroes.
+    fn zeroize(&mut self) {
+        for index in self.index_iter() {
+            self.remove(index);
+        }
+        crate::types::zeroize_storage(&mut self.data);
+    }
+}
+
+impl<A, N> ZeroizeOnDrop for SparseChunk<A, N> where N: Bits + ChunkLength<A> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use typenum::U32;
+
+    #[test]
+    fn zeroize_wipes_full_capacity() {
+        let mut chunk: SparseChunk<u8, U32> = SparseChunk::new();
+        for index in 0..32 {
+            chunk.insert(index, index as u8 + 1);
+        }
+        chunk.remove(5);
+        chunk.zeroize();
+        assert!(chunk.is_empty());
+        let storage =
+            unsafe { std::slice::from_raw_parts(&chunk.data as *const _ as *const u8, 32) };
+        assert!(storage.iter().all(|byte| *byte == 0));
+    }
+}
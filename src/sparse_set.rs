@@ -0,0 +1,518 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+//! A fixed capacity set of indices.
+//!
+//! See [`SparseSet`](struct.SparseSet.html)
+
+use std::fmt::{Debug, Error, Formatter};
+use std::hash::{Hash, Hasher};
+use std::iter::{FromIterator, FusedIterator};
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign,
+};
+
+use bitmaps::{Bitmap, Bits};
+use typenum::U64;
+
+use crate::sparse_chunk::SparseChunk;
+use crate::types::ChunkLength;
+
+/// A fixed capacity set of indices.
+///
+/// A `SparseSet<N>` holds any subset of the indices `0..N`, stored as a single
+/// [`Bitmap`][Bitmap]. It's what a [`SparseChunk`][SparseChunk] of the same
+/// size would look like if it only stored the bitmap and no values, and you
+/// can convert freely between the two.
+///
+/// As well as the usual set operations, it supports [`rank`][rank], which
+/// counts the indices below a given index, and [`select`][select], which finds
+/// the index at a given rank. Together, these map the indices of a sparse
+/// array to the positions of its values in a dense one.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sized_chunks::SparseSet;
+/// # use typenum::U32;
+/// let mut set: SparseSet<U32> = vec![3, 5, 8].into_iter().collect();
+/// assert!(set.insert(13));
+/// assert!(set.contains(5));
+/// assert_eq!(2, set.rank(8));
+/// assert_eq!(Some(8), set.select(2));
+/// ```
+///
+/// [Bitmap]: https://docs.rs/bitmaps/latest/bitmaps/struct.Bitmap.html
+/// [SparseChunk]: ../sparse_chunk/struct.SparseChunk.html
+/// [rank]: #method.rank
+/// [select]: #method.select
+pub struct SparseSet<N: Bits = U64> {
+    map: Bitmap<N>,
+}
+
+impl<N: Bits> SparseSet<N> {
+    /// The number of indices a `SparseSet` can hold.
+    pub const CAPACITY: usize = N::USIZE;
+
+    /// Construct a new empty set.
+    pub fn new() -> Self {
+        SparseSet { map: Bitmap::new() }
+    }
+
+    /// Construct a set containing every index from `0` to `N - 1`.
+    pub fn full() -> Self {
+        if N::USIZE == 0 {
+            return Self::new();
+        }
+        let mut map = Bitmap::mask(N::USIZE - 1);
+        map.set(N::USIZE - 1, true);
+        SparseSet { map }
+    }
+
+    /// Get the set's bitmap.
+    #[inline]
+    pub fn as_bitmap(&self) -> Bitmap<N> {
+        self.map
+    }
+
+    /// Get the number of indices in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Test if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Test if the set contains every index.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() == N::USIZE
+    }
+
+    /// Test if an index is in the set.
+    ///
+    /// Indices out of bounds are never in the set.
+    pub fn contains(&self, index: usize) -> bool {
+        index < N::USIZE && self.map.get(index)
+    }
+
+    /// Add an index to the set.
+    ///
+    /// Returns `true` if the index wasn't already in the set.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn insert(&mut self, index: usize) -> bool {
+        if index >= N::USIZE {
+            panic!("SparseSet::insert: index out of bounds");
+        }
+        !self.map.set(index, true)
+    }
+
+    /// Remove an index from the set.
+    ///
+    /// Returns `true` if the index was in the set.
+    pub fn remove(&mut self, index: usize) -> bool {
+        index < N::USIZE && self.map.set(index, false)
+    }
+
+    /// Remove every index from the set.
+    pub fn clear(&mut self) {
+        self.map = Bitmap::new();
+    }
+
+    /// Get the lowest index in the set.
+    pub fn first(&self) -> Option<usize> {
+        self.map.first_index()
+    }
+
+    /// Remove the lowest index from the set and return it.
+    pub fn pop_first(&mut self) -> Option<usize> {
+        let index = self.map.first_index()?;
+        self.map.set(index, false);
+        Some(index)
+    }
+
+    /// Get an iterator over the indices in the set, in ascending order.
+    pub fn iter(&self) -> Iter<N> {
+        Iter::new(self.map)
+    }
+
+    /// Count the indices in the set which are lower than `index`.
+    ///
+    /// If `index` is in the set, this is its position in the sequence of
+    /// indices, and the position of its value in a dense array of the set's
+    /// values.
+    ///
+    /// Panics if `index` is greater than `N`.
+    pub fn rank(&self, index: usize) -> usize {
+        if index > N::USIZE {
+            panic!("SparseSet::rank: index out of bounds");
+        }
+        if index == N::USIZE {
+            self.len()
+        } else {
+            (self.map & Bitmap::mask(index)).len()
+        }
+    }
+
+    /// Find the index with the given rank, ie. the `rank`th lowest index in
+    /// the set, counting from zero.
+    ///
+    /// This is the inverse of [`rank`][rank].
+    ///
+    /// [rank]: #method.rank
+    pub fn select(&self, rank: usize) -> Option<usize> {
+        self.iter().nth(rank)
+    }
+
+    /// Make a set of the indices which are in either `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        SparseSet {
+            map: self.map | other.map,
+        }
+    }
+
+    /// Make a set of the indices which are in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        SparseSet {
+            map: self.map & other.map,
+        }
+    }
+
+    /// Make a set of the indices which are in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+
+    /// Make a set of the indices which are in either `self` or `other` but not
+    /// in both.
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        SparseSet {
+            map: self.map ^ other.map,
+        }
+    }
+
+    /// Make a set of the indices which are not in `self`.
+    pub fn complement(&self) -> Self {
+        // Inverting the bitmap also sets any unused bits in its backing
+        // store, so we have to mask those back out.
+        SparseSet {
+            map: !self.map & Self::full().map,
+        }
+    }
+
+    /// Test if every index in `self` is also in `other`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Test if every index in `other` is also in `self`.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Test if `self` and `other` have no indices in common.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    /// Make a [`SparseChunk`][SparseChunk] with a value at each index in the
+    /// set, produced by calling `f` with the index.
+    ///
+    /// [SparseChunk]: ../sparse_chunk/struct.SparseChunk.html
+    pub fn to_sparse_chunk<A, F>(&self, mut f: F) -> SparseChunk<A, N>
+    where
+        N: ChunkLength<A>,
+        F: FnMut(usize) -> A,
+    {
+        let mut chunk = SparseChunk::new();
+        for index in self {
+            chunk.insert(index, f(index));
+        }
+        chunk
+    }
+}
+
+impl<N: Bits> Default for SparseSet<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Bits> Clone for SparseSet<N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N: Bits> Copy for SparseSet<N> {}
+
+impl<N: Bits> PartialEq for SparseSet<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<N: Bits> Eq for SparseSet<N> {}
+
+impl<N: Bits> Hash for SparseSet<N> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        for index in self {
+            index.hash(hasher);
+        }
+    }
+}
+
+impl<N: Bits> Debug for SparseSet<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<N: Bits> From<Bitmap<N>> for SparseSet<N> {
+    /// Make a set of the indices which are set in a bitmap.
+    ///
+    /// Panics if the bitmap has bits set outside its size.
+    fn from(map: Bitmap<N>) -> Self {
+        let set = SparseSet { map };
+        if set != set.intersection(&Self::full()) {
+            panic!("SparseSet::from: bitmap has bits set out of bounds");
+        }
+        set
+    }
+}
+
+impl<N: Bits> From<SparseSet<N>> for Bitmap<N> {
+    fn from(set: SparseSet<N>) -> Self {
+        set.map
+    }
+}
+
+impl<'a, A, N> From<&'a SparseChunk<A, N>> for SparseSet<N>
+where
+    N: Bits + ChunkLength<A>,
+{
+    /// Make a set of the indices which hold values in a sparse chunk.
+    fn from(chunk: &'a SparseChunk<A, N>) -> Self {
+        chunk.indices().collect()
+    }
+}
+
+impl<N: Bits> FromIterator<usize> for SparseSet<N> {
+    /// Panics if an index is out of bounds.
+    fn from_iter<I>(it: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let mut set = Self::new();
+        set.extend(it);
+        set
+    }
+}
+
+impl<N: Bits> Extend<usize> for SparseSet<N> {
+    /// Panics if an index is out of bounds.
+    fn extend<I>(&mut self, it: I)
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        for index in it {
+            self.insert(index);
+        }
+    }
+}
+
+impl<N: Bits> IntoIterator for SparseSet<N> {
+    type Item = usize;
+    type IntoIter = Iter<N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<N: Bits> IntoIterator for &SparseSet<N> {
+    type Item = usize;
+    type IntoIter = Iter<N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<N: Bits> BitAnd for SparseSet<N> {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self::Output {
+        self.intersection(&other)
+    }
+}
+
+impl<N: Bits> BitOr for SparseSet<N> {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self::Output {
+        self.union(&other)
+    }
+}
+
+impl<N: Bits> BitXor for SparseSet<N> {
+    type Output = Self;
+
+    fn bitxor(self, other: Self) -> Self::Output {
+        self.symmetric_difference(&other)
+    }
+}
+
+impl<N: Bits> Sub for SparseSet<N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.difference(&other)
+    }
+}
+
+impl<N: Bits> Not for SparseSet<N> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl<N: Bits> BitAndAssign for SparseSet<N> {
+    fn bitand_assign(&mut self, other: Self) {
+        *self = *self & other;
+    }
+}
+
+impl<N: Bits> BitOrAssign for SparseSet<N> {
+    fn bitor_assign(&mut self, other: Self) {
+        *self = *self | other;
+    }
+}
+
+impl<N: Bits> BitXorAssign for SparseSet<N> {
+    fn bitxor_assign(&mut self, other: Self) {
+        *self = *self ^ other;
+    }
+}
+
+impl<N: Bits> SubAssign for SparseSet<N> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+/// An iterator over the indices in a [`SparseSet`][SparseSet] or a
+/// [`SparseChunk`][SparseChunk], in ascending order.
+///
+/// [SparseSet]: struct.SparseSet.html
+/// [SparseChunk]: ../sparse_chunk/struct.SparseChunk.html
+pub struct Iter<N: Bits> {
+    map: Bitmap<N>,
+}
+
+impl<N: Bits> Iter<N> {
+    pub(crate) fn new(map: Bitmap<N>) -> Self {
+        Iter { map }
+    }
+}
+
+impl<N: Bits> Iterator for Iter<N> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Finding the first index skips over empty words in the bitmap
+        // instead of testing each bit in turn.
+        let index = self.map.first_index()?;
+        self.map.set(index, false);
+        Some(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.map.len();
+        (len, Some(len))
+    }
+}
+
+impl<N: Bits> ExactSizeIterator for Iter<N> {}
+
+impl<N: Bits> FusedIterator for Iter<N> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use typenum::{U1, U20, U256, U8};
+
+    #[test]
+    fn insert_and_remove() {
+        let mut set: SparseSet<U20> = SparseSet::new();
+        assert!(set.insert(19));
+        assert!(set.insert(3));
+        assert!(!set.insert(3));
+        assert_eq!(2, set.len());
+        assert!(set.contains(3));
+        assert!(!set.contains(4));
+        assert!(!set.contains(20));
+        assert!(set.remove(3));
+        assert!(!set.remove(3));
+        assert_eq!(vec![19], set.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn set_algebra() {
+        let left: SparseSet<U20> = vec![1, 2, 3, 10].into_iter().collect();
+        let right: SparseSet<U20> = vec![3, 4, 10, 19].into_iter().collect();
+        let indices = |set: SparseSet<U20>| set.iter().collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 3, 4, 10, 19], indices(left | right));
+        assert_eq!(vec![3, 10], indices(left & right));
+        assert_eq!(vec![1, 2], indices(left - right));
+        assert_eq!(vec![1, 2, 4, 19], indices(left ^ right));
+        assert_eq!(16, left.complement().len());
+        assert!(!left.complement().contains(10));
+        assert!((left & right).is_subset(&left));
+        assert!(left.is_superset(&(left & right)));
+        assert!((left - right).is_disjoint(&right));
+        assert!(SparseSet::<U8>::full().complement().is_empty());
+        assert_eq!(vec![0], SparseSet::<U1>::full().iter().collect::<Vec<_>>());
+        assert_eq!(256, SparseSet::<U256>::full().len());
+    }
+
+    #[test]
+    fn rank_and_select() {
+        let set: SparseSet<U256> = vec![0, 127, 128, 200, 255].into_iter().collect();
+        assert_eq!(0, set.rank(0));
+        assert_eq!(1, set.rank(127));
+        assert_eq!(2, set.rank(128));
+        assert_eq!(4, set.rank(255));
+        assert_eq!(5, set.rank(256));
+        for (rank, index) in set.iter().enumerate() {
+            assert_eq!(rank, set.rank(index));
+            assert_eq!(Some(index), set.select(rank));
+        }
+        assert_eq!(None, set.select(5));
+    }
+
+    #[test]
+    fn sparse_chunk_conversion() {
+        let chunk = SparseChunk::<char, U20>::pair(4, 'a', 17, 'b');
+        let set = SparseSet::from(&chunk);
+        assert_eq!(set, chunk.indices().collect());
+        let doubled = set.to_sparse_chunk(|index| index * 2);
+        assert_eq!(Some(&34), doubled.get(17));
+        assert_eq!(2, doubled.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "SparseSet::insert: index out of bounds")]
+    fn insert_out_of_bounds() {
+        let mut set: SparseSet<U20> = SparseSet::new();
+        set.insert(20);
+    }
+}
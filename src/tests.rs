@@ -1,3 +1,4 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 pub(crate) struct DropTest<'a> {
@@ -11,8 +12,119 @@ impl<'a> DropTest<'a> {
     }
 }
 
+impl<'a> Clone for DropTest<'a> {
+    fn clone(&self) -> Self {
+        DropTest::new(self.counter)
+    }
+}
+
 impl<'a> Drop for DropTest<'a> {
     fn drop(&mut self) {
-        self.counter.fetch_sub(1, Ordering::Relaxed);
+        let previous = self.counter.fetch_sub(1, Ordering::Relaxed);
+        assert!(previous > 0, "DropTest::drop: value dropped twice");
+    }
+}
+
+/// A `DropTest` which can be made to panic when it's cloned.
+pub(crate) struct PanicOnClone<'a> {
+    value: DropTest<'a>,
+    panic: bool,
+}
+
+impl<'a> PanicOnClone<'a> {
+    pub(crate) fn new(counter: &'a AtomicUsize) -> Self {
+        PanicOnClone {
+            value: DropTest::new(counter),
+            panic: false,
+        }
+    }
+
+    /// Construct a value which panics when it's cloned.
+    pub(crate) fn panicking(counter: &'a AtomicUsize) -> Self {
+        PanicOnClone {
+            value: DropTest::new(counter),
+            panic: true,
+        }
+    }
+}
+
+impl<'a> Clone for PanicOnClone<'a> {
+    fn clone(&self) -> Self {
+        if self.panic {
+            panic!("PanicOnClone::clone: panic is set");
+        }
+        PanicOnClone {
+            value: self.value.clone(),
+            panic: false,
+        }
+    }
+}
+
+/// An iterator which claims to have `len` values left, but only yields
+/// `count` values before it either stops or panics.
+pub(crate) struct TestIter<I> {
+    iter: I,
+    len: usize,
+    count: usize,
+    panic: bool,
+}
+
+impl<I> TestIter<I> {
+    /// Construct an iterator which panics after yielding `count` values.
+    pub(crate) fn panic_after(iter: I, len: usize, count: usize) -> Self {
+        TestIter {
+            iter,
+            len,
+            count,
+            panic: true,
+        }
+    }
+
+    /// Construct an iterator which stops after yielding `count` values.
+    pub(crate) fn stop_after(iter: I, len: usize, count: usize) -> Self {
+        TestIter {
+            iter,
+            len,
+            count,
+            panic: false,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for TestIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count == 0 {
+            if self.panic {
+                panic!("TestIter::next: out of values");
+            }
+            return None;
+        }
+        self.count -= 1;
+        self.len = self.len.saturating_sub(1);
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
     }
 }
+
+impl<I: Iterator> ExactSizeIterator for TestIter<I> {}
+
+/// Run `f` on a thread with a 64 KiB stack, so that it fails if it ever
+/// puts one of the larger than 64 KiB collections it builds on the stack.
+pub(crate) fn on_small_stack<F: FnOnce() + Send + 'static>(f: F) {
+    std::thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(f)
+        .unwrap()
+        .join()
+        .unwrap()
+}
+
+/// Run `f`, asserting that it panics.
+pub(crate) fn assert_panics<F: FnOnce()>(f: F) {
+    assert!(catch_unwind(AssertUnwindSafe(f)).is_err());
+}
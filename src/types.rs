@@ -25,6 +25,8 @@ impl<A> ChunkLength<A> for UTerm {
 
 #[doc(hidden)]
 #[allow(dead_code)]
+#[derive(Clone, Copy)]
+#[repr(C)]
 pub struct SizeEven<A, B> {
     parent1: B,
     parent2: B,
@@ -33,6 +35,8 @@ pub struct SizeEven<A, B> {
 
 #[doc(hidden)]
 #[allow(dead_code)]
+#[derive(Clone, Copy)]
+#[repr(C)]
 pub struct SizeOdd<A, B> {
     parent1: B,
     parent2: B,
@@ -52,3 +56,172 @@ where
 {
     type SizedType = SizeOdd<A, N::SizedType>;
 }
+
+/// Overwrite the storage of a chunk with zeroes.
+///
+/// Unlike zeroizing the `MaybeUninit` directly, this doesn't build a zeroed
+/// copy of the storage on the stack first, which large chunks can't afford.
+#[cfg(feature = "zeroize")]
+pub(crate) fn zeroize_storage<T>(data: &mut std::mem::MaybeUninit<T>) {
+    use ::zeroize::Zeroize;
+    let bytes: &mut [std::mem::MaybeUninit<u8>] = unsafe {
+        std::slice::from_raw_parts_mut(data.as_mut_ptr().cast(), std::mem::size_of::<T>())
+    };
+    bytes.zeroize();
+}
+
+// Sequences
+
+/// A fixed capacity sequence of values.
+///
+/// This is implemented by [`Chunk`][Chunk], [`RingBuffer`][RingBuffer] and
+/// [`InlineArray`][InlineArray], so you can write code which works with any of
+/// them.
+///
+/// # Examples
+///
+/// ```rust
+/// # use sized_chunks::{Chunk, InlineArray, RingBuffer};
+/// # use sized_chunks::types::FixedSeq;
+/// fn fill<S: FixedSeq<Item = usize>>(seq: &mut S) {
+///     while !seq.is_full() {
+///         seq.push_back(seq.len());
+///     }
+/// }
+///
+/// let mut chunk: Chunk<usize> = Chunk::new();
+/// fill(&mut chunk);
+/// assert_eq!(64, chunk.len());
+///
+/// let mut array: InlineArray<usize, [usize; 8]> = InlineArray::new();
+/// fill(&mut array);
+/// assert_eq!(Some(&6), FixedSeq::get(&array, 6));
+/// ```
+///
+/// [Chunk]: ../sized_chunk/struct.Chunk.html
+/// [RingBuffer]: ../ring_buffer/struct.RingBuffer.html
+/// [InlineArray]: ../inline_array/struct.InlineArray.html
+pub trait FixedSeq {
+    /// The type of the values in the sequence.
+    type Item;
+
+    /// An iterator over references to the values in the sequence.
+    type Iter<'a>: Iterator<Item = &'a Self::Item>
+    where
+        Self: 'a;
+
+    /// An iterator which removes the values from the sequence as it goes.
+    type Drain<'a>: Iterator<Item = Self::Item>
+    where
+        Self: 'a;
+
+    /// The maximum number of values the sequence can contain.
+    const CAPACITY: usize;
+
+    /// Get the number of values in the sequence.
+    fn len(&self) -> usize;
+
+    /// Test if the sequence is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Test if the sequence is at capacity.
+    fn is_full(&self) -> bool {
+        self.len() == Self::CAPACITY
+    }
+
+    /// Push a value to the back of the sequence.
+    ///
+    /// Panics if the sequence is full.
+    fn push_back(&mut self, value: Self::Item);
+
+    /// Pop a value off the back of the sequence.
+    ///
+    /// Returns `None` if the sequence is empty.
+    fn pop_back(&mut self) -> Option<Self::Item>;
+
+    /// Get a reference to the value at a given index.
+    fn get(&self, index: usize) -> Option<&Self::Item>;
+
+    /// Insert a value at a given index, shifting the values after it.
+    ///
+    /// Panics if the index is out of bounds or the sequence is full.
+    fn insert(&mut self, index: usize, value: Self::Item);
+
+    /// Remove the value at a given index, shifting the values after it.
+    ///
+    /// Panics if the index is out of bounds.
+    fn remove(&mut self, index: usize) -> Self::Item;
+
+    /// Get an iterator over references to the values in the sequence.
+    fn iter(&self) -> Self::Iter<'_>;
+
+    /// Get an iterator which removes the values from the sequence as it goes.
+    fn drain(&mut self) -> Self::Drain<'_>;
+}
+
+/// A fixed capacity sequence which can grow and shrink at both ends.
+///
+/// This is implemented by [`Chunk`][Chunk] and [`RingBuffer`][RingBuffer].
+///
+/// [Chunk]: ../sized_chunk/struct.Chunk.html
+/// [RingBuffer]: ../ring_buffer/struct.RingBuffer.html
+pub trait DoubleEndedFixedSeq: FixedSeq {
+    /// Push a value to the front of the sequence.
+    ///
+    /// Panics if the sequence is full.
+    fn push_front(&mut self, value: Self::Item);
+
+    /// Pop a value off the front of the sequence.
+    ///
+    /// Returns `None` if the sequence is empty.
+    fn pop_front(&mut self) -> Option<Self::Item>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Chunk, InlineArray, RingBuffer};
+
+    fn rotate<S: DoubleEndedFixedSeq<Item = usize>>(seq: &mut S) {
+        if let Some(value) = seq.pop_back() {
+            seq.push_front(value);
+        }
+    }
+
+    fn exercise<S: FixedSeq<Item = usize> + Default>() -> Vec<usize> {
+        let mut seq = S::default();
+        for value in 0..4 {
+            seq.push_back(value);
+        }
+        seq.insert(1, 10);
+        assert_eq!(Some(&10), seq.get(1));
+        assert_eq!(2, seq.remove(3));
+        assert_eq!(Some(3), seq.pop_back());
+        assert_eq!(3, seq.len());
+        let values: Vec<_> = seq.iter().cloned().collect();
+        assert_eq!(values, seq.drain().collect::<Vec<_>>());
+        assert!(seq.is_empty());
+        values
+    }
+
+    #[test]
+    fn fixed_seq() {
+        assert_eq!(vec![0, 10, 1], exercise::<Chunk<usize, U8>>());
+        assert_eq!(vec![0, 10, 1], exercise::<RingBuffer<usize, U8>>());
+        assert_eq!(vec![0, 10, 1], exercise::<InlineArray<usize, [usize; 8]>>());
+        assert_eq!(7, <InlineArray<usize, [usize; 8]> as FixedSeq>::CAPACITY);
+    }
+
+    #[test]
+    fn double_ended_fixed_seq() {
+        let mut chunk: Chunk<usize, U4> = (0..3).collect();
+        let mut buffer: RingBuffer<usize, U4> = (0..3).collect();
+        rotate(&mut chunk);
+        rotate(&mut buffer);
+        assert_eq!(&[2, 0, 1], chunk.as_slice());
+        assert_eq!(buffer, chunk);
+        assert_eq!(Some(2), DoubleEndedFixedSeq::pop_front(&mut chunk));
+    }
+}